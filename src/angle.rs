@@ -0,0 +1,305 @@
+//! Angle units.
+//!
+//! The functions of the crate calculate angles in radians by default.
+//! The unit types below select the unit of the angle at compile time, through the [`AngleUnit`] trait.
+//! The scaling constants between units are calculated in `f64` at compile time, and converted to `T` once.
+//! Constants below one are applied as a division, so that no precision is lost in the conversion.
+//!
+//! The unit-aware functions of the crate, e.g. [`crate::atan::atan2_in`], fold the unit into the constants of the approximation,
+//! and calculate the angle in the unit directly instead of converting from radians.
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+use mixed_num::traits::*;
+
+/// Unit of an angle, defined by the size of a half turn.
+pub trait AngleUnit {
+    /// The size of a half turn, 180°, in the unit.
+    const HALF_TURN: f64;
+
+    /// The size of one radian in the unit.
+    const RADIAN: f64 = Self::HALF_TURN/core::f64::consts::PI;
+}
+
+/// Angle in radians, where a half turn is π.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Radians;
+
+/// Angle in degrees, where a half turn is 180.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Degrees;
+
+/// Angle in normalized turns, where a full turn is 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Turns;
+
+/// Binary angular measurement (BAM), where a half turn is 1.
+///
+/// The sign bit represents 180°, so that the angle spans the -1=<θ<1 range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bam;
+
+impl AngleUnit for Radians {
+    const HALF_TURN: f64 = core::f64::consts::PI;
+}
+
+impl AngleUnit for Degrees {
+    const HALF_TURN: f64 = 180.0;
+}
+
+impl AngleUnit for Turns {
+    const HALF_TURN: f64 = 0.5;
+}
+
+impl AngleUnit for Bam {
+    const HALF_TURN: f64 = 1.0;
+}
+
+/// Scale θ by a constant ratio.
+///
+/// Ratios below one are applied as a division by the reciprocal, so that the constant keeps its relative precision in `T`.
+fn scale<T>( theta: T, ratio: f64 ) -> T
+    where T: MixedNum + MixedOps
+{
+    if ratio < 1.0 {
+        return theta/T::mixed_from_num( 1.0/ratio );
+    }
+    return theta*T::mixed_from_num( ratio );
+}
+
+/// Get the size of a half turn in the unit `U`.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let half_turn = angle::half_turn::<angle::Degrees, FixedI32<U22>>();
+/// assert_eq!{ half_turn.to_num::<f32>(), 180.0 };
+/// ```
+pub fn half_turn<U, T>() -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps + MixedPi
+{
+    if U::HALF_TURN == core::f64::consts::PI {
+        return T::mixed_pi();
+    }
+    return T::mixed_from_num( U::HALF_TURN );
+}
+
+/// Convert an angle in radians to the unit `U`.
+///
+/// ## Arguments
+///
+/// * `theta` - The angle in radians.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let theta = angle::from_radians::<angle::Degrees, _>( FixedI32::<U22>::from_num(1) );
+/// assert_eq!{ theta.to_num::<f32>(), 57.29578 };
+/// ```
+pub fn from_radians<U, T>( theta: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps
+{
+    if U::HALF_TURN == core::f64::consts::PI {
+        return theta;
+    }
+    return scale( theta, U::RADIAN );
+}
+
+/// Convert an angle in the unit `U` to radians.
+///
+/// ## Arguments
+///
+/// * `theta` - The angle in the unit `U`.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let theta = angle::to_radians::<angle::Turns, _>( FixedI32::<U22>::from_num(0.25) );
+/// assert_eq!{ theta.to_num::<f32>(), 1.5707963 };
+/// ```
+pub fn to_radians<U, T>( theta: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps
+{
+    if U::HALF_TURN == core::f64::consts::PI {
+        return theta;
+    }
+    return scale( theta, 1.0/U::RADIAN );
+}
+
+/// Convert an angle from the unit `From` to the unit `To`.
+///
+/// ## Arguments
+///
+/// * `theta` - The angle in the unit `From`.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let theta = angle::convert::<angle::Degrees, angle::Bam, _>( FixedI32::<U22>::from_num(-90) );
+/// assert_eq!{ theta.to_num::<f32>(), -0.5 };
+/// ```
+pub fn convert<From, To, T>( theta: T ) -> T
+    where From: AngleUnit,
+          To:   AngleUnit,
+          T:    MixedNum + MixedOps
+{
+    if From::HALF_TURN == To::HALF_TURN {
+        return theta;
+    }
+    return scale( theta, To::HALF_TURN/From::HALF_TURN );
+}
+
+/// Round down to the closest integer.
+///
+/// The conversion to integer rounds towards -∞ for fixed-point types, and towards zero for floating point types.
+/// Saturates for arguments outside the range of `i64`.
+fn floor_i64<T>( t: T ) -> i64
+    where T: MixedNum + MixedOps
+{
+    let k: i64 = t.mixed_to_num();
+    if t < T::mixed_from_num(k) {
+        return k.saturating_sub(1);
+    }
+    return k;
+}
+
+/// Subtract k full turns from φ.
+///
+/// Subtracted as half turns, so that types which can not represent a full turn are supported.
+fn subtract_turns<T>( phi: T, k: i64, half_turn: T ) -> T
+    where T: MixedNum + MixedOps
+{
+    let k_half_turns = T::mixed_from_num(k)*half_turn;
+    return phi - k_half_turns - k_half_turns;
+}
+
+/// Wrapps θ to the -half turn=<θ<half turn range of the unit `U`, e.g. -180=<θ<180 for degrees.
+///
+/// Runs in constant time for fixed-point types. The number of turns is found by multiplying by the reciprocal of a full turn and rounding,
+/// and the turns are subtracted from θ. Arguments already inside the range are returned unchanged.
+/// The error grows with the number of turns subtracted, as the half turn is rounded to the resolution of `T`.
+///
+/// Floating point arguments larger than about 2^24 turns for `f32`, or 2^53 turns for `f64`, are reduced in a few passes.
+/// The result is inside the range, but carries no precision, as the resolution of the argument is coarser than a turn.
+/// Arguments so large that the subtracted turns are absorbed, such as `f32::MAX`, return zero.
+///
+/// ## Arguments
+///
+/// * `phi` - The unwrapped phase in the unit `U`.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let phi = angle::wrap_phase::<angle::Degrees, _>( FixedI32::<U22>::from_num(270) );
+/// assert_eq!{ phi.to_num::<f32>(), -90.0 };
+///
+/// let phi = angle::wrap_phase::<angle::Turns, _>( FixedI32::<U22>::from_num(1.75) );
+/// assert_eq!{ phi.to_num::<f32>(), -0.25 };
+///
+/// let phi = angle::wrap_phase::<angle::Radians, _>( FixedI32::<U22>::from_num(-500) );
+/// assert_eq!{ phi.to_num::<f32>(), 2.6548386 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of the phase wrapping in radians, compared to the `std::f64::rem_euclid` implementation.
+///
+/// The comparison is done for U22 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/wrap_phase_error.png?raw=true)
+///
+pub fn wrap_phase<U, T>( phi: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let half_turn = half_turn::<U, T>();
+
+    let mut theta = phi;
+    // A single pass suffices for fixed-point types. Floating point types round the subtracted turns for large arguments,
+    // and are reduced again until in range.
+    while half_turn <= theta || theta < -half_turn {
+        let turns = theta*T::mixed_from_num( 0.5/U::HALF_TURN ) + T::mixed_from_num(0.5);
+        let mut reduced = subtract_turns( theta, floor_i64( turns ), half_turn );
+
+        // Correct for the rounding of the reciprocal.
+        if half_turn <= reduced {
+            reduced = subtract_turns( reduced, 1, half_turn );
+        } else if reduced < -half_turn {
+            reduced = subtract_turns( reduced, -1, half_turn );
+        }
+
+        // The turn count saturates, and the subtraction is absorbed by the argument.
+        if reduced == theta {
+            return T::mixed_from_num(0);
+        }
+        theta = reduced;
+    }
+    return theta;
+}
+
+/// Wrapps θ to the 0=<θ<full turn range of the unit `U`, e.g. 0=<θ<360 for degrees.
+///
+/// Runs in constant time for fixed-point types, see [`wrap_phase`].
+///
+/// ## Arguments
+///
+/// * `phi` - The unwrapped phase in the unit `U`.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let phi = angle::wrap_phase_positive::<angle::Degrees, _>( FixedI32::<U22>::from_num(-90) );
+/// assert_eq!{ phi.to_num::<f32>(), 270.0 };
+///
+/// let phi = angle::wrap_phase_positive::<angle::Turns, _>( FixedI32::<U22>::from_num(1.75) );
+/// assert_eq!{ phi.to_num::<f32>(), 0.75 };
+/// ```
+pub fn wrap_phase_positive<U, T>( phi: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps + MixedPi
+{
+    let half_turn = half_turn::<U, T>();
+    let zero      = T::mixed_from_num(0);
+
+    let mut theta = phi;
+    // Reduced again until in range for floating point types, see [`wrap_phase`].
+    while theta < zero || half_turn <= theta - half_turn {
+        let turns = theta*T::mixed_from_num( 0.5/U::HALF_TURN );
+        let mut reduced = subtract_turns( theta, floor_i64( turns ), half_turn );
+
+        // Correct for the rounding of the reciprocal.
+        if half_turn <= reduced - half_turn {
+            reduced = subtract_turns( reduced, 1, half_turn );
+        } else if reduced < zero {
+            reduced = subtract_turns( reduced, -1, half_turn );
+        }
+
+        // The turn count saturates, and the subtraction is absorbed by the argument.
+        if reduced == theta {
+            return zero;
+        }
+        theta = reduced;
+    }
+    return theta;
+}
@@ -1,47 +1,47 @@
+#![allow(clippy::needless_return)]
+
 use mixed_num::traits::*;
+use crate::angle::{self, AngleUnit, Radians};
+use fixed::types::extra::U30;
+use fixed::FixedI32;
 
-/// Atan polynomial for below function.
+/// Atan polynomial for the below functions, `atan(r) ≈ r/(1 + 0.28125·r²)`, in the angle unit `U`.
+/// 
+/// The argument is the ratio of the smaller and larger of the two inputs, so that no intermediate result overflows.
+/// The size of a radian in the unit `U` is folded into the numerator at compile time.
 /// 
 /// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
 /// 
 /// ## Arguments 
 ///
-/// * `y` - Is the argument along the y or imaginary axis.
-/// * `x` - Is the argument along the x or real axis.
-/// 
-/// ## Example
+/// * `r` - Is the ratio, in the -1=<r=<1 range.
 /// 
-/// TODO 
-fn atan_poly_1<T>( y: T, x: T ) -> T
-    where T: MixedNum + MixedNumSigned + MixedPowi + MixedNumConversion<i32> + MixedNumConversion<f32> + MixedOps
+fn atan_poly<U, T>( r: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps
 {
-    let phi = (x*y) / ( x.mixed_powi(2) + T::mixed_from_num( 0.28125 )*y.mixed_powi(2) );
+    let phi = T::mixed_from_num( U::RADIAN )*r / ( T::mixed_from_num(1) + T::mixed_from_num( 0.28125 )*r*r );
     return phi;
 }
 
-/// Atan polynomial for below function.
-/// 
-/// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
-/// 
-/// ## Arguments 
-///
-/// * `y` - Is the argument along the y or imaginary axis.
-/// * `x` - Is the argument along the x or real axis.
-/// 
-/// ## Example
+/// Get the negative magnitude -|x|.
 /// 
-/// TODO 
-fn atan_poly_2<T>( y: T, x: T ) -> T
-    where T: MixedNum + MixedNumSigned + MixedPowi + MixedNumConversion<f32> + MixedOps
+/// Unlike |x|, -|x| is representable for the entire range of signed types, so that the magnitudes of the minimum value can be compared and divided.
+pub(crate) fn negative_abs<T>( x: T ) -> T
+    where T: MixedNum + MixedNumSigned + MixedOps
 {
-    let phi     = (x*y) / ( y.mixed_powi( 2) + T::mixed_from_num( 0.28125 )*x.mixed_powi(2) );
-    return phi;
+    if T::mixed_from_num(0) < x {
+        return -x;
+    }
+    return x;
 }
 
 
 /// Calculate atan2(y,x) using a selection of polynomial approximations, one for each octant in the unit circle.
 /// 
-/// The method is accurat within 0.028 degrees.
+/// The method is accurat within 0.28 degrees.
+/// 
+/// The polynomials are evaluated on the ratio of the smaller and larger input, so that the function is valid for the entire range of `T`.
 /// 
 /// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
 /// 
@@ -64,6 +64,9 @@ fn atan_poly_2<T>( y: T, x: T ) -> T
 /// 
 /// let arg = atan::atan2( FixedI32::<U28>::from_num(0.0), FixedI32::<U28>::from_num(0.0) );
 /// assert_eq!{ arg.to_num::<f32>(), 0.0 };
+/// 
+/// let arg = atan::atan2( FixedI32::<U28>::from_num(7.5), FixedI32::<U28>::from_num(-6.0) );
+/// assert_eq!{ arg.to_num::<f32>(), 2.2487624 };
 /// ``` 
 /// 
 /// ## Comparisons
@@ -74,56 +77,125 @@ fn atan_poly_2<T>( y: T, x: T ) -> T
 /// 
 pub fn atan2<T>( y: T, x: T ) -> T
     where T:  MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi + MixedReal + MixedPowi
+{
+    return atan2_in::<Radians, T>( y, x );
+}
+
+/// Calculate atan(x) using a polynomial approximation of `atan(x)`.
+/// 
+/// Utilizes a polynomial methodto estimate the angle θ \[radians\].
+/// Arguments outside the unit range use the reciprocal identity atan(x) = ±π/2 - atan(1/x), so that the function is valid for the entire range of `T`.
+/// 
+/// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
+/// 
+/// ## Arguments 
+///
+/// * `x` - Is the function argument.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let arg = atan::atan( FixedI32::<U28>::from_num(0.6)/FixedI32::<U28>::from_num(0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), 0.9782037 };
+/// 
+/// let arg = atan::atan( FixedI32::<U28>::MIN );
+/// assert_eq!{ arg.to_num::<f32>(), -1.4463432 };
+/// ``` 
+/// ## Comparisons
+/// 
+/// The figure below shows the function curve, and its error compared to the `std::f32::atan` implementation.
+/// 
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan_comparisons.png?raw=true)
+/// 
+/// The error of this function is compared to the error of the atan implementation in the cordic crate below.
+/// 
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan_error_comparisons.png?raw=true)
+/// 
+pub fn atan<T>( x: T ) -> T
+    where T:  MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi + MixedReal + MixedPowi
+{
+    return atan_in::<Radians, T>( x );
+}
+
+
+/// Calculate atan2(y,x) in the angle unit `U`, see [`atan2`].
+/// 
+/// The unit conversion is folded into the constants of the approximation at compile time, so that the angle is calculated in `U` without an additional rounding step.
+/// 
+/// ## Arguments 
+///
+/// * `y` - Is the argument along the y or imaginary axis.
+/// * `x` - Is the argument along the x or real axis.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let arg = atan::atan2_in::<angle::Degrees, _>( FixedI32::<U22>::from_num(0.6), FixedI32::<U22>::from_num(0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), 56.046944 };
+/// 
+/// let arg = atan::atan2_in::<angle::Turns, _>( FixedI32::<U22>::from_num(-0.6), FixedI32::<U22>::from_num(-0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), -0.34431386 };
+/// ``` 
+pub fn atan2_in<U, T>( y: T, x: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
 {
     // Precompute
-    let y_abs = y.mixed_abs();
-    let x_abs = x.mixed_abs();
+    let zero  = T::mixed_from_num(0);
+    let y_neg = negative_abs( y );
+    let x_neg = negative_abs( x );
 
-    let pi      = T::mixed_pi();
-    let pi_half = T::mixed_pi()/T::mixed_from_num(2);
+    let pi      = angle::half_turn::<U, T>();
+    let pi_half = pi/T::mixed_from_num(2);
 
     // Check which quadrant the result will land in.
     if  y == T::mixed_from_num(0)
     {
-        if  x.mixed_is_negative()
+        if  x < zero
         {
-            return T::mixed_pi();
+            return pi;
         }
         else
         {
             return T::mixed_from_num( 0 );
         }
     }
-    else if y.mixed_is_positive()
+    else if zero < y
     {
         if  x == T::mixed_from_num( 0 )
         {
-            return T::mixed_pi()/T::mixed_from_num( 2 );
+            return pi_half;
         }
-        else if x.mixed_is_positive()
+        else if zero < x
         {
             // First octant.
-            if y_abs - x_abs < T::mixed_from_num( 0.0 )
+            if x_neg < y_neg
             {
-                return atan_poly_1( y, x );
+                return atan_poly::<U, T>( y/x );
             }
             // Second octant.
             else
             {
-                return pi_half -atan_poly_2( y, x );
+                return pi_half -atan_poly::<U, T>( x/y );
             }
         }
         else
         {
             // Third octant.
-            if T::mixed_from_num( 0.0 ) <= y_abs - x_abs
+            if y_neg <= x_neg
             {
-                return pi_half - atan_poly_2( y, x );
+                return pi_half - atan_poly::<U, T>( x/y );
             }
             // Fourth octant.
             else
             {
-                return pi + atan_poly_1( y, x );
+                return pi + atan_poly::<U, T>( y/x );
             }
         }
     }
@@ -131,42 +203,40 @@ pub fn atan2<T>( y: T, x: T ) -> T
     {
         if  x == T::mixed_from_num( 0 )
         {
-            return -T::mixed_pi()/T::mixed_from_num( 2 );
+            return -pi_half;
         }
-        if x.mixed_is_positive()
+        if zero < x
         {
             // Fifth octant.
-            if y_abs - x_abs < T::mixed_from_num( 0.0 )
+            if x_neg < y_neg
             {
-                return atan_poly_1( y, x );
+                return atan_poly::<U, T>( y/x );
             }
             // Sixth octant.
             else
             {
-                return - pi_half -atan_poly_2( y, x );
+                return - pi_half -atan_poly::<U, T>( x/y );
             }
         }
         else
         {
             // Seventh octant.
-            if T::mixed_from_num( 0.0 ) <= y_abs - x_abs
+            if y_neg <= x_neg
             {
-                return -pi_half - atan_poly_2( y, x );
+                return -pi_half - atan_poly::<U, T>( x/y );
             }
             // Eigth octant.
             else
             {
-                return -pi + atan_poly_1( y, x );
+                return -pi + atan_poly::<U, T>( y/x );
             }
         }
     }
 }
 
-/// Calculate atan(x) using a polynomial approximation of `atan(x)`.
-/// 
-/// Utilizes a polynomial methodto estimate the angle θ \[radians\].
+/// Calculate atan(x) in the angle unit `U`, see [`atan`].
 /// 
-/// \[1\] R. G. Lyons, Streamlining Digital Signal Processing, Second Edition, IEEE Press, 2012.
+/// The unit conversion is folded into the constants of the approximation at compile time, so that the angle is calculated in `U` without an additional rounding step.
 /// 
 /// ## Arguments 
 ///
@@ -176,23 +246,280 @@ pub fn atan2<T>( y: T, x: T ) -> T
 /// 
 /// ```
 /// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let arg = atan::atan_in::<angle::Bam, _>( FixedI32::<U22>::from_num(-2) );
+/// assert_eq!{ arg.to_num::<f32>(), -0.35130072 };
+/// ``` 
+pub fn atan_in<U, T>( x: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let one     = T::mixed_from_num(1);
+    let pi_half = angle::half_turn::<U, T>()/T::mixed_from_num(2);
+
+    // Use the reciprocal identity atan(x) = ±π/2 - atan(1/x) outside the unit range.
+    if one < x {
+        return pi_half - atan_poly::<U, T>( one/x );
+    } else if x < -one {
+        return -pi_half - atan_poly::<U, T>( one/x );
+    }
+    return atan_poly::<U, T>( x );
+}
+
+/// Fast atan polynomial for the 0=<x=<1 range, `atan(x) ≈ π/4·x - x(x-1)·(0.2447+0.0663x)`, in the angle unit `U`.
+/// 
+/// The size of a radian in the unit `U` is folded into the coefficients at compile time.
+/// 
+/// \[1\] S. Rajan, S. Wang, R. Inkol and A. Joyal, "Efficient approximations for the arctangent function," IEEE Signal Processing Magazine, 2006.
+fn atan_fast_poly<U, T>( x: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedOps + MixedPi
+{
+    let one = T::mixed_from_num(1);
+    let phi = angle::half_turn::<U, T>()/T::mixed_from_num(4)*x - x*(x-one)*( T::mixed_from_num(0.2447*U::RADIAN) + T::mixed_from_num(0.0663*U::RADIAN)*x );
+    return phi;
+}
+
+/// Calculate atan2(y,x) using a fast, low order polynomial.
+/// 
+/// Cheaper than [`atan2`], for loops where an accuracy of about 0.1 degrees is sufficient.
+/// The polynomial is evaluated on the ratio of the smaller and larger input, and is accurate within 0.086 degrees.
+/// 
+/// \[1\] S. Rajan, S. Wang, R. Inkol and A. Joyal, "Efficient approximations for the arctangent function," IEEE Signal Processing Magazine, 2006.
+/// 
+/// ## Arguments 
+///
+/// * `y` - Is the argument along the y or imaginary axis.
+/// * `x` - Is the argument along the x or real axis.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
 /// use fixed::{types::extra::U28, FixedI32};
 /// 
-/// let arg = atan::atan( FixedI32::<U28>::from_num(0.6)/FixedI32::<U28>::from_num(0.4) );
-/// assert_eq!{ arg.to_num::<f32>(), 0.9782037 };
+/// let arg = atan::atan2_fast( FixedI32::<U28>::from_num(0.6), FixedI32::<U28>::from_num(0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), 0.98299754 };
+/// 
+/// let arg = atan::atan2_fast( FixedI32::<U28>::from_num(-0.6), FixedI32::<U28>::from_num(-0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), -2.158595 };
 /// ``` 
+/// 
 /// ## Comparisons
 /// 
-/// The figure below shows the function curve, and its error compared to the `std::f32::atan` implementation.
+/// The figure below shows the function curve, and its error compared to the `std::f32::atan2` implementation.
 /// 
-/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan_comparisons.png?raw=true)
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan2_fast_comparisons.png?raw=true)
 /// 
-/// The error of this function is compared to the error of the atan implementation in the cordic crate below.
+pub fn atan2_fast<T>( y: T, x: T ) -> T
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi
+{
+    return atan2_fast_in::<Radians, T>( y, x );
+}
+
+/// Calculate atan2(y,x) in the angle unit `U`, see [`atan2_fast`].
 /// 
-/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan_error_comparisons.png?raw=true)
+/// The unit conversion is folded into the constants of the approximation at compile time, so that the angle is calculated in `U` without an additional rounding step.
 /// 
-pub fn atan<T>( x: T ) -> T
-    where T:  MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi + MixedReal + MixedPowi
+/// ## Arguments 
+///
+/// * `y` - Is the argument along the y or imaginary axis.
+/// * `x` - Is the argument along the x or real axis.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let arg = atan::atan2_fast_in::<angle::Degrees, _>( FixedI32::<U22>::from_num(0.6), FixedI32::<U22>::from_num(0.4) );
+/// assert_eq!{ arg.to_num::<f32>(), 56.321606 };
+/// ``` 
+pub fn atan2_fast_in<U, T>( y: T, x: T ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi
+{
+    return atan2_octant::<U, T, _>( y, x, atan_fast_poly::<U, T> );
+}
+
+/// Method used in [`atan2_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    /// The octant polynomials of [`atan2`]. Max error 0.28°.
+    Octant,
+    /// Eleventh order minimax polynomial, evaluated on the ratio of the smaller and larger magnitude. Max error 9.6*10⁻⁵°.
+    Minimax,
+    /// Lookup table of 129 entries with linear interpolation, evaluated on the ratio of the smaller and larger magnitude. Max error 2.9*10⁻⁴°.
+    Lut,
+    /// CORDIC in vectoring mode with the given number of iterations, see [`crate::circular::atan2_magnitude`].
+    /// The error is about 2⁻ⁿ radians for n iterations, e.g. 8.8*10⁻⁶° for 24 iterations in U28.
+    Cordic(usize),
+}
+
+/// Odd coefficients c1, c3, .. of the minimax polynomial for atan(x) in the 0=<x=<1 range.
+/// Max error 1.7*10⁻⁶ radians.
+const ATAN_COEFFICIENTS: [f64; 6] = [ 0.9999772190799163, -0.3326228278407496, 0.1935403757729598,
+                                      -0.11642648118471723, 0.0526473506160219, -0.011719135406045413 ];
+
+/// Number of fractional bits used for the index into [`ATAN_LUT`].
+const LUT_BITS: u32 = 7;
+
+/// atan(k/128) for k = 0, 1, .. 128, in Q30.
+const ATAN_LUT: [i32; 129] = [ 0,    8388437,   16775851,   25161218,   33543516,   41921726,   50294833,   58661822,
+    67021687,   75373424,   83716036,   92048532,  100369930,  108679253,  116975536,  125257820,
+    133525159,  141776614,  150011262,  158228185,  166426484,  174605269,  182763663,  190900805,
+    199015846,  207107953,  215176309,  223220110,  231238569,  239230917,  247196400,  255134279,
+    263043837,  270924369,  278775192,  286595638,  294385059,  302142824,  309868320,  317560955,
+    325220151,  332845353,  340436023,  347991640,  355511705,  362995735,  370443267,  377853855,
+    385227074,  392562515,  399859787,  407118521,  414338361,  421518973,  428660037,  435761254,
+    442822340,  449843028,  456823070,  463762232,  470660297,  477517067,  484332355,  491105994,
+    497837829,  504527723,  511175551,  517781204,  524344587,  530865619,  537344232,  543780370,
+    550173994,  556525073,  562833591,  569099543,  575322936,  581503788,  587642129,  593737999,
+    599791448,  605802536,  611771334,  617697921,  623582386,  629424828,  635225352,  640984073,
+    646701114,  652376604,  658010682,  663603492,  669155185,  674665921,  680135863,  685565182,
+    690954054,  696302662,  701611191,  706879836,  712108791,  717298260,  722448447,  727559563,
+    732631822,  737665442,  742660643,  747617650,  752536690,  757417995,  762261796,  767068330,
+    771837835,  776570551,  781266719,  785926586,  790550395,  795138394,  799690833,  804207961,
+    808690030,  813137292,  817549999,  821928406,  826272767,  830583337,  834860371,  839104126,
+    843314857 ];
+
+/// Calculate atan(x) from the lookup table with linear interpolation, for 0=<x=<1.
+fn atan_lut<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    let x_bits = FixedI32::<U30>::saturating_from_num(x).to_bits() as i64;
+    let frac_bits = 30 - LUT_BITS;
+
+    let index = (x_bits >> frac_bits) as usize;
+    if ATAN_LUT.len()-1 <= index {
+        return T::from_num( FixedI32::<U30>::from_bits( ATAN_LUT[ATAN_LUT.len()-1] ) );
+    }
+
+    let frac = x_bits & ((1 << frac_bits) - 1);
+    let y0 = ATAN_LUT[index] as i64;
+    let y1 = ATAN_LUT[index+1] as i64;
+    let y  = y0 + (((y1-y0)*frac) >> frac_bits);
+    return T::from_num( FixedI32::<U30>::from_bits( y as i32 ) );
+}
+
+/// Calculate atan2(y,x) in the angle unit `U` by reducing the argument to the first octant.
+///
+/// The arctangent of the ratio of the smaller and larger magnitude, in the 0=<r=<1 range, is found by `atan_unit` in the unit `U`.
+fn atan2_octant<U, T, F>( y: T, x: T, atan_unit: F ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi,
+          F: Fn(T) -> T
+{
+    let y_neg = negative_abs( y );
+    let x_neg = negative_abs( x );
+
+    let zero = T::mixed_from_num(0);
+    if y_neg == zero && x_neg == zero {
+        return zero;
+    }
+
+    let mut theta: T;
+    if x_neg <= y_neg {
+        theta = atan_unit( y_neg/x_neg );
+    } else {
+        theta = angle::half_turn::<U, T>()/T::mixed_from_num(2) - atan_unit( x_neg/y_neg );
+    }
+
+    if x < zero {
+        theta = angle::half_turn::<U, T>() - theta;
+    }
+    if y < zero {
+        theta = -theta;
+    }
+    return theta;
+}
+
+/// Calculate atan2(y,x) using a selectable method.
+/// 
+/// The methods trade accuracy for computational cost, see [`Method`] for the error bound of each method.
+/// 
+/// ## Arguments 
+///
+/// * `y`      - Is the argument along the y or imaginary axis.
+/// * `x`      - Is the argument along the x or real axis.
+/// * `method` - The method of calculation.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let y = FixedI32::<U28>::from_num(0.6);
+/// let x = FixedI32::<U28>::from_num(-0.4);
+/// 
+/// let arg = atan::atan2_with( y, x, atan::Method::Minimax );
+/// assert_eq!{ arg.to_num::<f32>(), 2.1587994 };
+/// 
+/// let arg = atan::atan2_with( y, x, atan::Method::Lut );
+/// assert_eq!{ arg.to_num::<f32>(), 2.1587946 };
+/// 
+/// let arg = atan::atan2_with( y, x, atan::Method::Cordic(24) );
+/// assert_eq!{ arg.to_num::<f32>(), 2.158799 };
+/// ``` 
+/// 
+/// ## Comparisons
+/// 
+/// The figure below shows the error of each method compared to the `std::f64::atan2` implementation.
+/// 
+/// The comparison is done for U28 signed fixed point.
+/// 
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/atan2_method_error_comparison.png?raw=true)
+/// 
+pub fn atan2_with<T>( y: T, x: T, method: Method ) -> T
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi + MixedReal + MixedPowi
+{
+    return atan2_with_in::<Radians, T>( y, x, method );
+}
+
+/// Calculate atan2(y,x) in the angle unit `U` using a selectable method, see [`atan2_with`].
+/// 
+/// The unit conversion is folded into the coefficients of the octant, minimax and lookup table methods.
+/// The CORDIC method calculates the angle in radians, which is then converted with [`angle::from_radians`].
+/// 
+/// ## Arguments 
+///
+/// * `y`      - Is the argument along the y or imaginary axis.
+/// * `x`      - Is the argument along the x or real axis.
+/// * `method` - The method of calculation.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let y = FixedI32::<U22>::from_num(0.6);
+/// let x = FixedI32::<U22>::from_num(-0.4);
+/// 
+/// let arg = atan::atan2_with_in::<angle::Degrees, _>( y, x, atan::Method::Minimax );
+/// assert_eq!{ arg.to_num::<f32>(), 123.6901 };
+/// 
+/// let arg = atan::atan2_with_in::<angle::Turns, _>( y, x, atan::Method::Lut );
+/// assert_eq!{ arg.to_num::<f32>(), 0.34358263 };
+/// 
+/// let arg = atan::atan2_with_in::<angle::Degrees, _>( y, x, atan::Method::Cordic(24) );
+/// assert_eq!{ arg.to_num::<f32>(), 123.69015 };
+/// ``` 
+pub fn atan2_with_in<U, T>( y: T, x: T, method: Method ) -> T
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi
 {
-    return atan2(x,T::mixed_from_num(1));
-}
\ No newline at end of file
+    match method
+    {
+        Method::Octant     => return atan2_in::<U, T>( y, x ),
+        Method::Minimax    => {
+            let coefficients = ATAN_COEFFICIENTS.map( |c| c*U::RADIAN );
+            return atan2_octant::<U, T, _>( y, x, |r| crate::minimax::odd_polynomial( r, &coefficients ) );
+        }
+        Method::Lut        => return atan2_octant::<U, T, _>( y, x, |r| T::mixed_from_num( U::RADIAN )*atan_lut::<T>( r ) ),
+        // Halve the arguments, so that the magnitude of the vector is representable in the CORDIC iterations.
+        Method::Cordic(n)  => return angle::from_radians::<U, T>( crate::circular::atan2_magnitude( y >> 1, x >> 1, n ).0 ),
+    }
+}
@@ -0,0 +1,263 @@
+//! Fixed-point CORDIC in circular mode.
+//!
+//! Rotation mode drives the residual angle to zero, yielding sin, cos and polar to cartesian conversion.
+//! Vectoring mode drives the imaginary part to zero, yielding the angle and magnitude of a vector in one pass.
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+use mixed_num::traits::*;
+use num::complex::Complex;
+use fixed::types::extra::U62;
+use fixed::FixedI64;
+
+/// The maximum number of CORDIC iterations. Larger iteration counts are clamped to this value,
+/// and to one less than the number of bits of `T`, beyond which the shifted values are zero.
+pub const MAX_ITERATIONS: usize = 48;
+
+/// The elementary angles atan(2⁻ⁱ), in Q2.62 format.
+const ATAN_TABLE: [i64; MAX_ITERATIONS] = [
+    0x3243F6A8885A308D, 0x1DAC670561BB4F69, 0x0FADBAFC96406EB1, 0x07F56EA6AB0BDB72,
+    0x03FEAB76E59FBD39, 0x01FFD55BBA97624B, 0x00FFFAAADDDB94D6, 0x007FFF5556EEEA5D,
+    0x003FFFEAAAB7776E, 0x001FFFFD5555BBBC, 0x000FFFFFAAAAADDE, 0x0007FFFFF555556F,
+    0x0003FFFFFEAAAAAB, 0x0001FFFFFFD55555, 0x0000FFFFFFFAAAAB, 0x00007FFFFFFF5555,
+    0x00003FFFFFFFEAAB, 0x00001FFFFFFFFD55, 0x00000FFFFFFFFFAB, 0x000007FFFFFFFFF5,
+    0x000003FFFFFFFFFF, 0x0000020000000000, 0x0000010000000000, 0x0000008000000000,
+    0x0000004000000000, 0x0000002000000000, 0x0000001000000000, 0x0000000800000000,
+    0x0000000400000000, 0x0000000200000000, 0x0000000100000000, 0x0000000080000000,
+    0x0000000040000000, 0x0000000020000000, 0x0000000010000000, 0x0000000008000000,
+    0x0000000004000000, 0x0000000002000000, 0x0000000001000000, 0x0000000000800000,
+    0x0000000000400000, 0x0000000000200000, 0x0000000000100000, 0x0000000000080000,
+    0x0000000000040000, 0x0000000000020000, 0x0000000000010000, 0x0000000000008000,
+];
+
+/// The inverse CORDIC gain 1/K after n iterations, stored at index n-1, in Q2.62 format.
+const INVERSE_GAIN: [i64; MAX_ITERATIONS] = [
+    0x2D413CCCFE779921, 0x287A26C490921DB6, 0x2744C374DAF46D30, 0x26F72283BD67FBDB,
+    0x26E3B58305DDEB19, 0x26DED9F57B2C3E7B, 0x26DDA30D3E4FD186, 0x26DD5552E1641DEF,
+    0x26DD41E4454DA117, 0x26DD3D089DFA47C8, 0x26DD3BD1B42095CF, 0x26DD3B83F9A9DB96,
+    0x26DD3B708B0C282C, 0x26DD3B6BAF64BB04, 0x26DD3B6A787ADFB5, 0x26DD3B6A2AC068E1,
+    0x26DD3B6A1751CB2C, 0x26DD3B6A127623BE, 0x26DD3B6A113F39E3, 0x26DD3B6A10F17F6C,
+    0x26DD3B6A10DE10CF, 0x26DD3B6A10D93527, 0x26DD3B6A10D7FE3D, 0x26DD3B6A10D7B083,
+    0x26DD3B6A10D79D14, 0x26DD3B6A10D79839, 0x26DD3B6A10D79702, 0x26DD3B6A10D796B4,
+    0x26DD3B6A10D796A0, 0x26DD3B6A10D7969C, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A,
+    0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A,
+    0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A,
+    0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A,
+    0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A, 0x26DD3B6A10D7969A,
+];
+
+/// Convert a Q2.62 table entry to the target type.
+fn from_q62<T>( bits: i64 ) -> T
+    where T: fixed::traits::Fixed
+{
+    return T::from_num( FixedI64::<U62>::from_bits( bits ) );
+}
+
+/// Clamp the iteration count to [`MAX_ITERATIONS`], and below the number of bits of `T` so that the shifts do not overflow.
+fn clamp_iterations<T>( iterations: usize ) -> usize
+    where T: fixed::traits::Fixed
+{
+    let nbits = (T::INT_NBITS + T::FRAC_NBITS) as usize;
+    return iterations.min( MAX_ITERATIONS ).min( nbits-1 );
+}
+
+/// The inverse gain for the selected number of iterations.
+fn inverse_gain<T>( iterations: usize ) -> T
+    where T: fixed::traits::Fixed
+{
+    if iterations == 0 {
+        return T::from_num(1);
+    }
+    return from_q62( INVERSE_GAIN[iterations-1] );
+}
+
+/// Rotate the vector (x, y) by the angle θ using CORDIC in rotation mode.
+///
+/// The input vector is pre-scaled by the inverse CORDIC gain, so the output has the magnitude of the input.
+///
+/// ## Arguments
+///
+/// * `x`          - Is the argument along the x or real axis.
+/// * `y`          - Is the argument along the y or imaginary axis.
+/// * `theta`      - The angle to rotate by, in radians.
+/// * `iterations` - The number of CORDIC iterations. Each iteration adds about one bit of accuracy.
+///
+/// `theta` must be wrapped to the -π=<x<π range.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let (x, y) = circular::rotate( FixedI32::<U28>::from_num(1), FixedI32::<U28>::from_num(0), FixedI32::<U28>::from_num(3.1415926/2.0), 24 );
+/// assert_eq!{ x.to_num::<f32>(), -7.0780516e-8 };
+/// assert_eq!{ y.to_num::<f32>(), 1.0 };
+/// ```
+pub fn rotate<T>( x: T, y: T, theta: T, iterations: usize ) -> (T, T)
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let n = clamp_iterations::<T>( iterations );
+
+    let pi_half = T::mixed_pi()/T::mixed_from_num(2);
+
+    // Rotation mode converges for |θ| < 1.74. Rotate by ±π up front for the rest of the circle.
+    let mut z = theta;
+    let mut x_ = x*inverse_gain::<T>(n);
+    let mut y_ = y*inverse_gain::<T>(n);
+    if pi_half < z {
+        z  = z - T::mixed_pi();
+        x_ = -x_;
+        y_ = -y_;
+    } else if z < -pi_half {
+        z  = z + T::mixed_pi();
+        x_ = -x_;
+        y_ = -y_;
+    }
+
+    for (i, atan) in ATAN_TABLE.iter().enumerate().take(n)
+    {
+        let x_shift = x_ >> i as u32;
+        let y_shift = y_ >> i as u32;
+        let angle: T = from_q62( *atan );
+
+        if z.is_negative() {
+            x_ = x_ + y_shift;
+            y_ = y_ - x_shift;
+            z  = z + angle;
+        } else {
+            x_ = x_ - y_shift;
+            y_ = y_ + x_shift;
+            z  = z - angle;
+        }
+    }
+    return (x_, y_);
+}
+
+/// Calculate sin(θ) and cos(θ) in one pass, using CORDIC in rotation mode.
+///
+/// ## Arguments
+///
+/// * `theta`      - The angle in radians.
+/// * `iterations` - The number of CORDIC iterations. Each iteration adds about one bit of accuracy.
+///
+/// `theta` must be wrapped to the -π=<x<π range.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let (sin, cos) = circular::sin_cos( FixedI32::<U28>::from_num(1), 24 );
+/// assert_eq!{ sin.to_num::<f32>(), 0.841471 };
+/// assert_eq!{ cos.to_num::<f32>(), 0.5403023 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of the CORDIC sine with 24 iterations, compared to the polynomial [`crate::sin`] and the cordic crate.
+///
+/// The comparison is done for U22 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/circular_sine_error_comparison.png?raw=true)
+///
+pub fn sin_cos<T>( theta: T, iterations: usize ) -> (T, T)
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let (cos, sin) = rotate( T::from_num(1), T::from_num(0), theta, iterations );
+    return (sin, cos);
+}
+
+/// Convert a complex number in polar form to cartesian form, using CORDIC in rotation mode.
+///
+/// ## Arguments
+///
+/// * `r`          - The magnitude.
+/// * `theta`      - The angle in radians.
+/// * `iterations` - The number of CORDIC iterations. Each iteration adds about one bit of accuracy.
+///
+/// `theta` must be wrapped to the -π=<x<π range.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U24, FixedI32};
+///
+/// let c = circular::polar_to_cartesian( FixedI32::<U24>::from_num(2), FixedI32::<U24>::from_num(-2.0), 24 );
+/// assert_eq!{ c.re.to_num::<f32>(), -0.8322931 };
+/// assert_eq!{ c.im.to_num::<f32>(), -1.818595 };
+/// ```
+pub fn polar_to_cartesian<T>( r: T, theta: T, iterations: usize ) -> Complex<T>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let (re, im) = rotate( r, T::from_num(0), theta, iterations );
+    return Complex::new( re, im );
+}
+
+/// Calculate atan2(y,x) and the magnitude of (x, y) in one pass, using CORDIC in vectoring mode.
+///
+/// The input vector is pre-scaled by the inverse CORDIC gain, so the magnitude does not grow beyond that of the input.
+/// The magnitude sqrt(x²+y²) must be representable in `T`, see [`crate::atan::atan2_with`] for the angle over the entire range of `T`.
+///
+/// ## Arguments
+///
+/// * `y`          - Is the argument along the y or imaginary axis.
+/// * `x`          - Is the argument along the x or real axis.
+/// * `iterations` - The number of CORDIC iterations. Each iteration adds about one bit of accuracy.
+///
+/// Returns the tuple (θ, r) where θ is in the -π=<θ=<π range.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let (theta, r) = circular::atan2_magnitude( FixedI32::<U28>::from_num(0.6), FixedI32::<U28>::from_num(-0.4), 24 );
+/// assert_eq!{ theta.to_num::<f32>(), 2.1587989 };
+/// assert_eq!{ r.to_num::<f32>(), 0.7211103 };
+/// ```
+pub fn atan2_magnitude<T>( y: T, x: T, iterations: usize ) -> (T, T)
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let n = clamp_iterations::<T>( iterations );
+
+    if x == T::from_num(0) && y == T::from_num(0) {
+        return (T::from_num(0), T::from_num(0));
+    }
+
+    let mut x_ = x*inverse_gain::<T>(n);
+    let mut y_ = y*inverse_gain::<T>(n);
+    let mut z  = T::from_num(0);
+
+    // Vectoring mode converges in the right half-plane. Rotate by ±π up front for the left half-plane.
+    if x_.is_negative() {
+        if y_.is_negative() {
+            z = -T::mixed_pi();
+        } else {
+            z = T::mixed_pi();
+        }
+        x_ = -x_;
+        y_ = -y_;
+    }
+
+    for (i, atan) in ATAN_TABLE.iter().enumerate().take(n)
+    {
+        let x_shift = x_ >> i as u32;
+        let y_shift = y_ >> i as u32;
+        let angle: T = from_q62( *atan );
+
+        if y_.is_negative() {
+            x_ = x_ - y_shift;
+            y_ = y_ + x_shift;
+            z  = z - angle;
+        } else {
+            x_ = x_ + y_shift;
+            y_ = y_ - x_shift;
+            z  = z + angle;
+        }
+    }
+    return (z, x_);
+}
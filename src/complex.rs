@@ -1,4 +1,32 @@
+//! Complex numbers in cartesian and polar form.
+//!
+//! ## Overflow
+//!
+//! The plain operations, e.g. [`add`] and [`mul_cartesian`], overflow like the underlying arithmetic of `T`.
+//! For fixed-point types this panics in debug builds and wraps in release builds.
+//! The `checked_*`, `saturating_*` and `wrapping_*` variants handle overflow explicitly, like the methods of the same names in [`fixed`].
+//! The cartesian arithmetic, i.e. addition, subtraction, multiplication, division, [`recip`], [`norm_sqr`], [`conj`], [`mul_j`], [`mul_neg_j`] and [`powi`], has all three variants.
+//!
+//! In polar form only the magnitude can overflow, as the angle is always wrapped.
+//! [`mul_polar`], [`div_polar`], [`powi_polar`] and [`Polar::powi`] therefore have checked and saturating variants, but no wrapping variants.
+//! The `*` and `/` operators of [`Polar`] behave as [`mul_polar`] and [`div_polar`].
+//! The functions that round or approximate rather than overflow, e.g. [`abs`], [`to_polar`], [`exp`] and [`sqrt`], have no variants.
+//!
+//! ## Division by zero
+//!
+//! - The plain divisions [`div_cartesian`], [`div`] and [`recip`] return the maximum value of `T` in both parts.
+//!   The wrapping variants do the same, also when the denominator of [`wrapping_div`] wraps to zero.
+//! - The checked variants return `None`.
+//! - The saturating variants saturate each part towards the sign of the numerator:
+//!   the maximum value of `T` for a positive part, the minimum value for a negative part, and zero for a zero part.
+//! - In polar form, [`Polar::inv`] and [`div_polar`] saturate the magnitude to the maximum value of `T`.
+//!   [`checked_div_polar`] returns `None`, and [`saturating_div_polar`] saturates the magnitude towards the sign of the numerator.
+
+#![allow(clippy::needless_return, clippy::redundant_field_names, clippy::needless_late_init)]
+
 use crate::atan;
+use crate::angle::{self, AngleUnit, Radians};
+use core::marker::PhantomData;
 use num::complex::Complex;
 use mixed_num::*;
 
@@ -10,13 +38,35 @@ use mixed_num::*;
 /// * `x` - The number transform.
 ///
 pub fn to_polar<T>( x: Complex<T> ) -> Polar<T>
-    where T:  MixedNum + MixedNumSigned + MixedSqrt + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi
+    where T:  MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi
 {
-    let c_polar = Polar::<T>{
-        r:     abs(x),
-        theta: atan::atan2( x.im, x.re )
-    };
-    return c_polar;
+    return to_polar_in::<Radians, T>( x );
+}
+
+/// Cast cartesian complex fixed point number to polar form, with the angle in the unit `U`.
+/// 
+/// ## Arguments
+/// 
+/// * `x` - The number transform.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::{angle, complex::*};
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let x = Complex{re:FixedI32::<U22>::from_num(0), im:FixedI32::<U22>::from_num(-2)};
+/// let y = to_polar_in::<angle::Degrees, _>( x );
+/// assert_eq!{ y.r.to_num::<f32>(), 2.0 };
+/// assert_eq!{ y.theta.to_num::<f32>(), -90.0 };
+/// ``` 
+///
+pub fn to_polar_in<U, T>( x: Complex<T> ) -> Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi
+{
+    return Polar::new( abs(x), atan::atan2_in::<U, T>( x.im, x.re ) );
 }
 
 /// Calculate the absolute value of the argument.
@@ -35,17 +85,305 @@ pub fn to_polar<T>( x: Complex<T> ) -> Polar<T>
 /// assert_eq!{ abs(x), 1f32 };
 /// ``` 
 /// 
+/// The magnitude is calculated with [`crate::hypot`], which is valid over the full range of `T`.
+/// Unsigned fixed-point types are supported.
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::{U16, U28}, FixedI32, FixedU32};
+/// 
+/// let x = Complex{re:FixedU32::<U16>::from_num(30000), im:FixedU32::<U16>::from_num(40000)};
+/// assert_eq!{ abs(x).to_num::<f32>(), 50000.0 };
+/// 
+/// let x = Complex{re:FixedI32::<U28>::from_num(4), im:FixedI32::<U28>::from_num(-3)};
+/// assert_eq!{ abs(x).to_num::<f32>(), 5.0 };
+/// ``` 
+/// 
 pub fn abs<T>( a: Complex<T> ) -> T
-where T: MixedNum + MixedNumSigned + MixedSqrt + MixedOps
+where T: MixedNum + MixedOps + MixedAbs + MixedReal
 {
-    let r_sqr = super::powi( a.re, 2) + super::powi( a.im, 2);
-    return r_sqr.mixed_sqrt();
+    return crate::hypot( a.re, a.im );
 }
 
 /// Polar complex nuber.
-pub struct Polar<T> {
+/// 
+/// The angle is in the unit `U`, radians by default. See [`crate::angle`] for the available units.
+/// 
+/// Multiplication and division are implemented with the `*` and `/` operators, and wrap the resulting angle with [`angle::wrap_phase`].
+/// `T` needs the range of a full turn for the intermediate angle.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let a = Polar::<FixedI32<U22>>::new( FixedI32::<U22>::from_num(2), FixedI32::<U22>::from_num(3) );
+/// let b = Polar::<FixedI32<U22>>::new( FixedI32::<U22>::from_num(0.5), FixedI32::<U22>::from_num(1) );
+/// 
+/// let c = a*b;
+/// assert_eq!{ c.r.to_num::<f32>(), 1.0 };
+/// assert_eq!{ c.theta.to_num::<f32>(), -2.2831855 };
+/// 
+/// let c = a/b;
+/// assert_eq!{ c.r.to_num::<f32>(), 4.0 };
+/// assert_eq!{ c.theta.to_num::<f32>(), 2.0 };
+/// 
+/// let x: Complex<FixedI32<U22>> = b.into();
+/// assert_eq!{ x.re.to_num::<f32>(), 0.2701509 };
+/// ``` 
+/// 
+/// ## Construction
+/// 
+/// The unit is carried by the `unit` marker field. Construct the number with [`Polar::new`], which is a `const fn`.
+/// Struct literals need the marker, `Polar{ r, theta, unit: PhantomData }`, and the `Polar{ r, theta }` literal of earlier releases no longer compiles.
+/// 
+/// ```
+/// use core::marker::PhantomData;
+/// use fixed_trigonometry::complex::*;
+/// 
+/// const X: Polar<f32> = Polar::new( 1f32, 0.5f32 );
+/// assert_eq!{ X, Polar{ r: 1f32, theta: 0.5f32, unit: PhantomData } };
+/// ``` 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polar<T, U = Radians> {
     pub r: T,
     pub theta: T,
+    pub unit: PhantomData<U>,
+}
+
+impl<T, U> Polar<T, U>
+    where U: AngleUnit
+{
+    /// Create a polar complex number.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `r`     - The magnitude.
+    /// * `theta` - The angle in the unit `U`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::{angle, complex::*};
+    /// 
+    /// let x = Polar::<f32, angle::Degrees>::new( 2f32, 45f32 );
+    /// assert_eq!{ x.theta, 45f32 };
+    /// ``` 
+    pub const fn new( r: T, theta: T ) -> Self
+    {
+        return Polar{ r: r, theta: theta, unit: PhantomData };
+    }
+
+    /// Convert the angle to the unit `V`.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::{angle, complex::*};
+    /// use fixed::{types::extra::U22, FixedI32};
+    /// 
+    /// let x = Polar::<_, angle::Degrees>::new( FixedI32::<U22>::from_num(1), FixedI32::<U22>::from_num(90) );
+    /// let y = x.to_unit::<angle::Turns>();
+    /// assert_eq!{ y.theta.to_num::<f32>(), 0.25 };
+    /// ``` 
+    pub fn to_unit<V>( self ) -> Polar<T, V>
+        where V: AngleUnit,
+              T: MixedNum + MixedOps
+    {
+        return Polar::new( self.r, angle::convert::<U, V, T>( self.theta ) );
+    }
+}
+
+impl<T, U> Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi + MixedReal
+{
+    /// The complex conjugate, with the angle negated.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::{angle, complex::*};
+    /// 
+    /// let x = Polar::<f32, angle::Degrees>::new( 2f32, 180f32 );
+    /// assert_eq!{ x.conj(), Polar::new( 2f32, -180f32 ) };
+    /// ``` 
+    pub fn conj( &self ) -> Self
+    {
+        // Wrap before negating, as the negation of the minimum value of the type overflows.
+        let theta = angle::wrap_phase::<U, T>( self.theta );
+        return Polar::new( self.r, angle::wrap_phase::<U, T>( -theta ) );
+    }
+
+    /// The reciprocal, 1/x.
+    /// 
+    /// The magnitude saturates to the maximum value of `T` for a zero magnitude.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::{angle, complex::*};
+    /// 
+    /// let x = Polar::<f32, angle::Degrees>::new( 4f32, 90f32 );
+    /// assert_eq!{ x.inv(), Polar::new( 0.25f32, -90f32 ) };
+    /// ``` 
+    pub fn inv( &self ) -> Self
+    {
+        let r: T;
+        if self.r == T::mixed_from_num(0) {
+            r = T::mixed_max_value();
+        } else {
+            r = T::mixed_from_num(1)/self.r;
+        }
+        return Polar::new( r, self.conj().theta );
+    }
+
+    /// Rase the number to an integer power, `x^power`.
+    /// 
+    /// The magnitude is calculated with [`crate::powi`]. The angle is multiplied by repeated doubling,
+    /// wrapping in each step, so that the angle does not overflow for large powers.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `power` - The power to raise the number to.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::{angle, complex::*};
+    /// use fixed::{types::extra::U22, FixedI32};
+    /// 
+    /// let x = Polar::<_, angle::Degrees>::new( FixedI32::<U22>::from_num(2), FixedI32::<U22>::from_num(100) );
+    /// let y = x.powi(5);
+    /// assert_eq!{ y.r.to_num::<f32>(), 32.0 };
+    /// assert_eq!{ y.theta.to_num::<f32>(), 140.0 };
+    /// 
+    /// let y = x.powi(-1);
+    /// assert_eq!{ y.r.to_num::<f32>(), 0.5 };
+    /// assert_eq!{ y.theta.to_num::<f32>(), -100.0 };
+    /// ``` 
+    pub fn powi( &self, power: i32 ) -> Self
+    {
+        return Polar::new( crate::powi( self.r, power ), powi_angle::<U, T>( self.theta, power ) );
+    }
+}
+
+impl<T, U> Polar<T, U>
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    /// Rase the number to an integer power, `x^power`, returning `None` if the magnitude overflows.
+    /// 
+    /// The magnitude is calculated with [`crate::checked_powi`]. The angle is wrapped as in [`Polar::powi`], and does not overflow.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::complex::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    /// 
+    /// type F = FixedI32<U28>;
+    /// 
+    /// let x = Polar::<F>::new( F::from_num(2), F::from_num(1) );
+    /// assert_eq!{ x.checked_powi(2).unwrap().r.to_num::<f32>(), 4.0 };
+    /// assert_eq!{ x.checked_powi(3), None };
+    /// ``` 
+    pub fn checked_powi( &self, power: i32 ) -> Option<Self>
+    {
+        return Some( Polar::new( crate::checked_powi( self.r, power )?, powi_angle::<U, T>( self.theta, power ) ) );
+    }
+
+    /// Rase the number to an integer power, `x^power`, saturating the magnitude on overflow.
+    /// 
+    /// The magnitude is calculated with [`crate::saturating_powi`]. The angle is wrapped as in [`Polar::powi`], and does not overflow.
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use fixed_trigonometry::complex::*;
+    /// use fixed::{types::extra::U28, FixedI32};
+    /// 
+    /// type F = FixedI32<U28>;
+    /// 
+    /// let x = Polar::<F>::new( F::from_num(2), F::from_num(1) );
+    /// let y = x.saturating_powi(3);
+    /// assert_eq!{ y.r, F::MAX };
+    /// assert_eq!{ y.theta.to_num::<f32>(), 3.0 };
+    /// ``` 
+    pub fn saturating_powi( &self, power: i32 ) -> Self
+    {
+        return Polar::new( crate::saturating_powi( self.r, power ), powi_angle::<U, T>( self.theta, power ) );
+    }
+}
+
+/// Multiply an angle by an integer power by repeated doubling, wrapping in each step.
+fn powi_angle<U, T>( theta: T, power: i32 ) -> T
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let mut n     = power.unsigned_abs();
+    let mut theta = theta;
+    let mut phi   = T::mixed_from_num(0);
+
+    while 0 < n {
+        if n & 1 == 1 {
+            phi = angle::wrap_phase::<U, T>( phi + theta );
+        }
+        theta = angle::wrap_phase::<U, T>( theta + theta );
+        n >>= 1;
+    }
+
+    if power < 0 {
+        phi = angle::wrap_phase::<U, T>( -phi );
+    }
+    return phi;
+}
+
+impl<T, U> core::ops::Mul for Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    type Output = Self;
+
+    fn mul( self, rhs: Self ) -> Self
+    {
+        return mul_polar( self, rhs );
+    }
+}
+
+impl<T, U> core::ops::Div for Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi + MixedReal
+{
+    type Output = Self;
+
+    fn div( self, rhs: Self ) -> Self
+    {
+        return div_polar( self, rhs );
+    }
+}
+
+impl<T, U> From<Complex<T>> for Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi
+{
+    fn from( x: Complex<T> ) -> Self
+    {
+        return to_polar_in::<U, T>( x );
+    }
+}
+
+impl<T, U> From<Polar<T, U>> for Complex<T>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedTrigonometry + MixedOps + MixedPi
+{
+    fn from( x: Polar<T, U> ) -> Self
+    {
+        return to_cartsian( x );
+    }
 }
 
 /// Cast cartesian complex fixed point number to polar form.
@@ -60,14 +398,18 @@ pub struct Polar<T> {
 /// use num::complex::Complex;
 /// use fixed_trigonometry::complex::*;
 /// 
-/// let mut x = Polar{r:1f32, theta:0f32};
+/// let mut x = Polar::<f32>::new( 1f32, 0f32 );
 /// assert_eq!{ to_cartsian(x).to_string(), "1+0i" };
+/// 
+/// let mut x = Polar::<f32, fixed_trigonometry::angle::Turns>::new( 2f32, 0.25f32 );
+/// assert_eq!{ to_cartsian(x).to_string(), "-0.00000008742278+2i" };
 /// ``` 
 /// 
-pub fn to_cartsian<T>( a: Polar<T> ) -> Complex<T>
-    where T: MixedNum + MixedNumSigned + MixedTrigonometry + MixedOps + MixedPi
+pub fn to_cartsian<T, U>( a: Polar<T, U> ) -> Complex<T>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedTrigonometry + MixedOps + MixedPi
 {
-    let theta = crate::wrap_phase(a.theta);
+    let theta = crate::wrap_phase( angle::to_radians::<U, T>(a.theta) );
     let (imag_s, real_s) = theta.mixed_sincos();
 
     let c_cartesian = Complex::<T>{
@@ -104,27 +446,43 @@ pub fn sub<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
 
 /// Multiply fixed-point complex numbers in polar form.
 /// 
-pub fn mul_polar<T>( a: Polar<T>, b: Polar<T> ) -> Polar<T>
-    where T: MixedNum + MixedNumSigned + MixedOps
+/// The resulting angle is wrapped with [`angle::wrap_phase`].
+/// 
+pub fn mul_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi
 {
     if a.r==T::mixed_from_num(0) || b.r==T::mixed_from_num(0)
     {
-        let c = Polar::<T>{
-            r:     T::mixed_from_num(0),
-            theta: T::mixed_from_num(0)
-        };
+        let c = Polar::new( T::mixed_from_num(0), T::mixed_from_num(0) );
         return c;
     }
     else
     {
-        let c = Polar::<T>{
-            r:     a.r*b.r,
-            theta: a.theta+b.theta
-        };
+        let c = Polar::new( a.r*b.r, angle::wrap_phase::<U, T>( a.theta+b.theta ) );
         return c;
     }
 }
 
+/// Divide fixed-point complex numbers in polar form.
+/// c = a/b
+/// 
+/// The resulting angle is wrapped with [`angle::wrap_phase`].
+/// The magnitude saturates to the maximum value of `T` when b is zero.
+/// 
+pub fn div_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Polar<T, U>
+    where U: AngleUnit,
+          T: MixedNum + MixedNumSigned + MixedOps + MixedPi + MixedReal
+{
+    let r: T;
+    if b.r == T::mixed_from_num(0) {
+        r = T::mixed_max_value();
+    } else {
+        r = a.r/b.r;
+    }
+    return Polar::new( r, angle::wrap_phase::<U, T>( a.theta-b.theta ) );
+}
+
 /// Multiply two cartesian complex numbers.
 /// 
 pub fn mul_cartesian<T>( ab: Complex<T>, bc: Complex<T> ) -> Complex<T>
@@ -140,12 +498,16 @@ pub fn mul_cartesian<T>( ab: Complex<T>, bc: Complex<T> ) -> Complex<T>
     return Complex{re:re, im:im}
 }
 
-/// Rase a complex fixed-point number to an real-valued integer power.
+/// Rase a complex number to an integer power.
 /// `base^power`.
 /// 
+/// Calculated by exponentiation by squaring with cartesian multiplications, using O(log n) multiplications.
+/// The result is exact when the intermediate products are representable in `T`, e.g. for small powers of small integers.
+/// The rounding error grows with the power, see [`powi_polar`] for large powers.
+/// 
 /// ## Arguments
 /// 
-/// * `base`  - The complex, fixed-point base number.
+/// * `base`  - The complex base number.
 /// * `power` - The power to raise 'base' to.
 /// 
 /// ## Example
@@ -155,34 +517,79 @@ pub fn mul_cartesian<T>( ab: Complex<T>, bc: Complex<T> ) -> Complex<T>
 /// use fixed::{types::extra::U22, FixedI32};
 /// use num::complex::Complex;
 /// 
-/// 
 /// let x = Complex::new( FixedI32::<U22>::from_num(1), FixedI32::<U22>::from_num(1) );
 /// let y = trig::complex::powi( x, 2 );
 /// 
-/// let result = Complex::new( FixedI32::<U22>::from_num( -0.019603, ), FixedI32::<U22>::from_num( 1.9959388 ));
+/// let result = Complex::new( FixedI32::<U22>::from_num( 0 ), FixedI32::<U22>::from_num( 2 ));
 /// assert_eq!{ y, result };
+/// 
+/// let y = trig::complex::powi( Complex::new( 1f32, -2f32 ), 3 );
+/// assert_eq!{ y, Complex::new( -11f32, 2f32 ) };
 /// ```
 /// 
-pub fn powi<T>( base: num::complex::Complex<T>, power:usize ) -> num::complex::Complex<T>
-    where T: fixed::traits::FixedSigned + cordic::CordicNumber + MixedNum + MixedZero + MixedReal + MixedAbs + MixedPowi + MixedNumSigned + MixedOps + MixedPi
+pub fn powi<T>( base: Complex<T>, power:usize ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps
 {   
-    // Calculate raised magnitude.
-    let temp:T = super::powi( base.re, 2 ) + super::powi( base.im, 2 );
-    let mag:T  = super::powi( trigonometry::sqrt::niirf(temp, 2), power );
-
-    let phi:T  = super::atan::atan2( base.im, base.re )*<T>::from_num(power);
-
-    let (imag_s, real_s) = cordic::sin_cos( phi );
+    let mut n      = power;
+    let mut factor = base;
+    let mut result = Complex::new( T::mixed_from_num(1), T::mixed_from_num(0) );
 
-    let real   = mag*real_s;
-    let imag   = mag*imag_s;
+    while 0 < n
+    {
+        if n & 1 == 1
+        {
+            result = mul_cartesian( result, factor );
+        }
+        n >>= 1;
+        if 0 < n
+        {
+            factor = mul_cartesian( factor, factor );
+        }
+    }
+    return result;
+}
 
-    return num::complex::Complex::new( real, imag);
+/// Rase a complex number to an integer power through polar form.
+/// `base^power`.
+/// 
+/// The number is converted with [`to_polar`], raised with [`Polar::powi`] and converted back with [`to_cartsian`].
+/// Like [`powi`], the cost is O(log n) in the power, with real instead of complex multiplications.
+/// The result is inexact even for small powers, limited by the accuracy of [`atan::atan2`].
+/// Negative powers give the reciprocal, see [`Polar::inv`] for the magnitude of zero.
+/// 
+/// ## Arguments
+/// 
+/// * `base`  - The complex base number.
+/// * `power` - The power to raise 'base' to.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry as trig;
+/// use fixed::{types::extra::U22, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// let x = Complex::new( FixedI32::<U22>::from_num(1), FixedI32::<U22>::from_num(1) );
+/// let y = trig::complex::powi_polar( x, 2 );
+/// assert_eq!{ y.re.to_num::<f32>(), -0.019641876 };
+/// assert_eq!{ y.im.to_num::<f32>(), 1.999902 };
+/// 
+/// let y = trig::complex::powi_polar( x, -2 );
+/// assert_eq!{ y.re.to_num::<f32>(), -0.0049102306 };
+/// assert_eq!{ y.im.to_num::<f32>(), -0.49997592 };
+/// ```
+/// 
+pub fn powi_polar<T>( base: Complex<T>, power: i32 ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi + MixedTrigonometry
+{
+    return to_cartsian( to_polar( base ).powi( power ) );
 }
 
 /// Divide a cartesian complex by a real scalar.
 /// c = a/b
 /// 
+/// Division by zero returns the maximum value of `T` in both parts, see the [module documentation](self).
+/// 
 pub fn div_cartesian<T>( a: Complex<T>, b: T  ) -> Complex<T>
     where T: MixedNum + MixedReal + MixedNumSigned + MixedOps
 {
@@ -199,4 +606,1475 @@ pub fn div_cartesian<T>( a: Complex<T>, b: T  ) -> Complex<T>
         c.im = a.im / b;
     }
     return c;
-}
\ No newline at end of file
+}
+
+/// Multiply a cartesian complex by a real scalar.
+/// c = a·b
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// 
+/// let x = Complex::new( 1f32, -2f32 );
+/// assert_eq!{ scale( x, 3f32 ), Complex::new( 3f32, -6f32 ) };
+/// ``` 
+pub fn scale<T>( a: Complex<T>, b: T ) -> Complex<T>
+    where T: MixedNum + MixedOps
+{
+    return Complex::new( a.re*b, a.im*b );
+}
+
+/// The complex conjugate of a cartesian complex.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// 
+/// let x = Complex::new( 1f32, -2f32 );
+/// assert_eq!{ conj( x ), Complex::new( 1f32, 2f32 ) };
+/// ``` 
+pub fn conj<T>( a: Complex<T> ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps
+{
+    return Complex::new( a.re, -a.im );
+}
+
+/// Multiply a cartesian complex by j, rotating it by 90 degrees.
+/// 
+/// Implemented by swapping the real and imaginary parts, without multiplications.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// 
+/// let x = Complex::new( 1f32, -2f32 );
+/// assert_eq!{ mul_j( x ), Complex::new( 2f32, 1f32 ) };
+/// ``` 
+pub fn mul_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps
+{
+    return Complex::new( -a.im, a.re );
+}
+
+/// Multiply a cartesian complex by -j, rotating it by -90 degrees.
+/// 
+/// Implemented by swapping the real and imaginary parts, without multiplications.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// 
+/// let x = Complex::new( 1f32, -2f32 );
+/// assert_eq!{ mul_neg_j( x ), Complex::new( -2f32, -1f32 ) };
+/// ``` 
+pub fn mul_neg_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps
+{
+    return Complex::new( a.im, -a.re );
+}
+
+/// The squared magnitude of a cartesian complex, re²+im².
+/// 
+/// Cheaper than [`abs`], as no square root is needed.
+/// 
+/// Neither square exceeds the result, so no intermediate value overflows unless the result does.
+/// Pre-scaling by the larger part, as in [`div`], would therefore not extend the range:
+/// the result overflows when |z|² exceeds the maximum value of `T`, i.e. for magnitudes above sqrt(`T::MAX`).
+/// See [`checked_norm_sqr`], [`saturating_norm_sqr`] and [`wrapping_norm_sqr`] to handle the overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let x = Complex::new( FixedI32::<U22>::from_num(3), FixedI32::<U22>::from_num(-4) );
+/// assert_eq!{ norm_sqr( x ).to_num::<f32>(), 25.0 };
+/// 
+/// // |z|² = 481 is below the maximum value of U22, about 512.
+/// let x = Complex::new( FixedI32::<U22>::from_num(15), FixedI32::<U22>::from_num(-16) );
+/// assert_eq!{ norm_sqr( x ).to_num::<f32>(), 481.0 };
+/// ``` 
+pub fn norm_sqr<T>( a: Complex<T> ) -> T
+    where T: MixedNum + MixedOps
+{
+    return a.re*a.re + a.im*a.im;
+}
+
+/// The argument, or angle, of a cartesian complex in radians.
+/// 
+/// Calculated with [`atan::atan2`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let x = Complex::new( FixedI32::<U22>::from_num(-1), FixedI32::<U22>::from_num(0) );
+/// assert_eq!{ arg( x ).to_num::<f32>(), 3.1415927 };
+/// ``` 
+pub fn arg<T>( a: Complex<T> ) -> T
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedPi + MixedReal + MixedPowi
+{
+    return atan::atan2( a.im, a.re );
+}
+
+/// Divide two cartesian complex numbers.
+/// c = a/b
+/// 
+/// Uses Smith's algorithm \[1\], dividing by the larger of the real and imaginary parts of b first.
+/// This avoids forming |b|², so that the intermediate results do not overflow or lose precision for large or small b.
+/// The parts of b are compared by their negative magnitudes, which are representable for the minimum value of signed types.
+/// 
+/// Both parts are the maximum value of `T` when b is zero, see the [module documentation](self).
+/// 
+/// \[1\] R. L. Smith, "Algorithm 116: Complex division", Communications of the ACM, 1962.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let a = Complex::new( FixedI32::<U22>::from_num(1), FixedI32::<U22>::from_num(2) );
+/// let b = Complex::new( FixedI32::<U22>::from_num(3), FixedI32::<U22>::from_num(-4) );
+/// 
+/// let c = div( a, b );
+/// assert_eq!{ c.re.to_num::<f32>(), -0.19999981 };
+/// assert_eq!{ c.im.to_num::<f32>(), 0.39999986 };
+/// ``` 
+pub fn div<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedReal
+{
+    let zero = T::mixed_from_num(0);
+    if b.re == zero && b.im == zero
+    {
+        return Complex::new( T::mixed_max_value(), T::mixed_max_value() );
+    }
+
+    // |b.im| <= |b.re|
+    if atan::negative_abs( b.re ) <= atan::negative_abs( b.im )
+    {
+        let ratio = b.im/b.re;
+        let denom = b.re + b.im*ratio;
+        return Complex::new( (a.re + a.im*ratio)/denom, (a.im - a.re*ratio)/denom );
+    }
+    else
+    {
+        let ratio = b.re/b.im;
+        let denom = b.re*ratio + b.im;
+        return Complex::new( (a.re*ratio + a.im)/denom, (a.im*ratio - a.re)/denom );
+    }
+}
+
+/// The reciprocal of a cartesian complex, 1/a.
+/// 
+/// Calculated with [`div`], and saturates in the same way when a is zero.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let x = Complex::new( FixedI32::<U22>::from_num(0), FixedI32::<U22>::from_num(4) );
+/// let y = recip( x );
+/// assert_eq!{ y.re.to_num::<f32>(), 0.0 };
+/// assert_eq!{ y.im.to_num::<f32>(), -0.25 };
+/// ``` 
+pub fn recip<T>( a: Complex<T> ) -> Complex<T>
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedReal
+{
+    let one = Complex::new( T::mixed_from_num(1), T::mixed_from_num(0) );
+    return div( one, a );
+}
+
+/// The number of CORDIC iterations for the full resolution of `T`.
+fn cordic_iterations<T>() -> usize
+    where T: fixed::traits::Fixed
+{
+    return T::FRAC_NBITS as usize + 2;
+}
+
+/// The complex exponential, e^z = e^re·(cos(im) + j·sin(im)).
+/// 
+/// e^re is calculated with [`crate::exp::exp`], accurate to 2.0*10⁻⁹ relative to the result.
+/// cos(im) and sin(im) are calculated with [`crate::circular::sin_cos`], with one CORDIC iteration per fractional bit of `T`.
+/// The accuracy is further limited by the resolution of `T`. Both parts saturate for large real parts.
+/// 
+/// For U22 signed fixed point, the relative error is about 4.3*10⁻⁶ for -2<re<3.
+/// The relative error grows for negative real parts, as e^re approaches the resolution of `T`.
+/// 
+/// ## Arguments
+/// 
+/// * `z` - The exponent.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// // Mixer, e^(jθ).
+/// let z = Complex::new( FixedI32::<U22>::from_num(0), FixedI32::<U22>::from_num(2) );
+/// let y = complex::exp( z );
+/// assert_eq!{ y.re.to_num::<f32>(), -0.416147 };
+/// assert_eq!{ y.im.to_num::<f32>(), 0.9092982 };
+/// 
+/// let z = Complex::new( FixedI32::<U22>::from_num(1), FixedI32::<U22>::from_num(-9) );
+/// let y = complex::exp( z );
+/// assert_eq!{ y.re.to_num::<f32>(), -2.4767067 };
+/// assert_eq!{ y.im.to_num::<f32>(), -1.1202581 };
+/// ``` 
+pub fn exp<T>( z: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let magnitude  = crate::exp::exp( z.re );
+    let (sin, cos) = crate::circular::sin_cos( crate::wrap_phase( z.im ), cordic_iterations::<T>() );
+
+    return Complex::new( magnitude.saturating_mul(cos), magnitude.saturating_mul(sin) );
+}
+
+/// The principal value of the complex natural logarithm, ln(z) = ln|z| + j·arg(z).
+/// 
+/// |z| is calculated with [`crate::hypot`] and ln|z| with [`crate::log::ln`], accurate to 3.3*10⁻⁸.
+/// arg(z) is calculated with [`crate::circular::atan2_magnitude`], with one CORDIC iteration per fractional bit of `T`.
+/// The accuracy is further limited by the resolution of `T`, relative to |z| for the real part.
+/// For U22 signed fixed point, the absolute error is about 3.7*10⁻⁶ for |z|>1/7.
+/// 
+/// Arguments with a part at or beyond half the range of `T` are halved first, and ln 2 is added to the real part.
+/// This keeps |z| representable over the entire range of `T`.
+/// 
+/// The imaginary part is in the -π=<θ=<π range. A zero argument returns the minimum value of `T` for the real part, representing -∞.
+/// 
+/// ## Arguments
+/// 
+/// * `z` - The function argument.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let z = Complex::new( FixedI32::<U22>::from_num(-3), FixedI32::<U22>::from_num(4) );
+/// let y = complex::ln( z );
+/// assert_eq!{ y.re.to_num::<f32>(), 1.6094377 };
+/// assert_eq!{ y.im.to_num::<f32>(), 2.214297 };
+/// ``` 
+pub fn ln<T>( z: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedReal + MixedPi
+{
+    let zero = T::from_num(0);
+    if z.re == zero && z.im == zero {
+        return Complex::new( T::MIN, zero );
+    }
+
+    // Halve large arguments, so that the magnitude is representable in the CORDIC iterations, and ln|z| = ln|z/2| + ln 2.
+    let large = T::MIN >> 1;
+    if atan::negative_abs( z.re ) <= large || atan::negative_abs( z.im ) <= large {
+        let half = Complex::new( z.re >> 1, z.im >> 1 );
+        let (theta, _) = crate::circular::atan2_magnitude( half.im, half.re, cordic_iterations::<T>() );
+        return Complex::new( crate::log::ln( abs(half) ) + T::from_num( core::f64::consts::LN_2 ), theta );
+    }
+
+    let (theta, _) = crate::circular::atan2_magnitude( z.im, z.re, cordic_iterations::<T>() );
+    return Complex::new( crate::log::ln( abs(z) ), theta );
+}
+
+/// The principal square root of a complex number, with a non-negative real part.
+/// 
+/// Calculated as t = sqrt((|z|+|re|)/2), with |z| from [`crate::hypot`] and the square root by [`crate::sqrt::niirf`] with four iterations.
+/// The result is (t, im/2t) for a non-negative real part, and (|im|/2t, ±t) otherwise, which avoids cancellation.
+/// The relative error is about 3.2*10⁻⁶, limited by the resolution of `T`.
+/// |z| saturates to the maximum value of `T`, which limits the accuracy for larger magnitudes.
+/// 
+/// ## Arguments
+/// 
+/// * `z` - The function argument.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use num::complex::Complex;
+/// use fixed_trigonometry::complex;
+/// use fixed::{types::extra::U22, FixedI32};
+/// 
+/// let z = Complex::new( FixedI32::<U22>::from_num(-3), FixedI32::<U22>::from_num(-4) );
+/// let y = complex::sqrt( z );
+/// assert_eq!{ y.re.to_num::<f32>(), 0.99999857 };
+/// assert_eq!{ y.im.to_num::<f32>(), -2.0000029 };
+/// 
+/// let z = Complex::new( FixedI32::<U22>::from_num(-4), FixedI32::<U22>::from_num(0) );
+/// let y = complex::sqrt( z );
+/// assert_eq!{ y.re.to_num::<f32>(), 0.0 };
+/// assert_eq!{ y.im.to_num::<f32>(), 2.0000029 };
+/// ``` 
+pub fn sqrt<T>( z: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedAbs + MixedReal
+{
+    let zero = T::from_num(0);
+    if z.re == zero && z.im == zero {
+        return Complex::new( zero, zero );
+    }
+
+    // Halve before adding, so that the sum does not overflow. |re|/2 is negated from -|re|/2, which is representable for the minimum value.
+    let re_abs_half = -(atan::negative_abs( z.re ) >> 1);
+    let t = crate::sqrt::niirf( (abs(z) >> 1) + re_abs_half, 4 );
+    let u = (z.im/t) >> 1;
+
+    if zero <= z.re {
+        return Complex::new( t, u );
+    } else if z.im < zero {
+        return Complex::new( -u, -t );
+    }
+    return Complex::new( u, t );
+}
+
+/// Saturate a value towards its sign, for division by zero.
+fn saturate_sign<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    if x < T::from_num(0) {
+        return T::MIN;
+    } else if T::from_num(0) < x {
+        return T::MAX;
+    } else {
+        return T::from_num(0);
+    }
+}
+
+/// Saturate each part towards its sign, for division by zero.
+fn saturate_towards<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( saturate_sign( a.re ), saturate_sign( a.im ) );
+}
+
+/// Add two complex fixed-point numbers in cartesian form, returning `None` on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ checked_add( a, b ), None };
+/// assert_eq!{ checked_add( b, b ).unwrap().im.to_num::<f32>(), 2.0 };
+/// ```
+pub fn checked_add<T>( a: Complex<T>, b: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    return Some( Complex::new( a.re.checked_add( b.re )?, a.im.checked_add( b.im )? ) );
+}
+
+/// Add two complex fixed-point numbers in cartesian form, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ saturating_add( a, b ).re, F::MAX };
+/// ```
+pub fn saturating_add<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.saturating_add( b.re ), a.im.saturating_add( b.im ) );
+}
+
+/// Add two complex fixed-point numbers in cartesian form, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ wrapping_add( a, b ).re.to_num::<f32>(), -7.0 };
+/// ```
+pub fn wrapping_add<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.wrapping_add( b.re ), a.im.wrapping_add( b.im ) );
+}
+
+/// Subtract b from a in cartesian form, c = a-b, returning `None` on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(-7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ checked_sub( a, b ), None };
+/// assert_eq!{ checked_sub( b, b ).unwrap().im.to_num::<f32>(), 0.0 };
+/// ```
+pub fn checked_sub<T>( a: Complex<T>, b: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    return Some( Complex::new( a.re.checked_sub( b.re )?, a.im.checked_sub( b.im )? ) );
+}
+
+/// Subtract b from a in cartesian form, c = a-b, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(-7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ saturating_sub( a, b ).re, F::MIN };
+/// ```
+pub fn saturating_sub<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.saturating_sub( b.re ), a.im.saturating_sub( b.im ) );
+}
+
+/// Subtract b from a in cartesian form, c = a-b, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(-7), F::from_num(1) );
+/// let b = Complex::new( F::from_num(2), F::from_num(1) );
+/// assert_eq!{ wrapping_sub( a, b ).re.to_num::<f32>(), 7.0 };
+/// ```
+pub fn wrapping_sub<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.wrapping_sub( b.re ), a.im.wrapping_sub( b.im ) );
+}
+
+/// Multiply two cartesian complex numbers, returning `None` on overflow.
+/// 
+/// Returns `None` if any of the products or sums overflow, even if the result is representable.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(3) );
+/// let b = Complex::new( F::from_num(1), F::from_num(-1) );
+/// assert_eq!{ checked_mul_cartesian( a, b ).unwrap(), Complex::new( F::from_num(5), F::from_num(1) ) };
+/// assert_eq!{ checked_mul_cartesian( a, a ), None };
+/// ```
+pub fn checked_mul_cartesian<T>( a: Complex<T>, b: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    let re = a.re.checked_mul( b.re )?.checked_sub( a.im.checked_mul( b.im )? )?;
+    let im = a.re.checked_mul( b.im )?.checked_add( a.im.checked_mul( b.re )? )?;
+    return Some( Complex::new( re, im ) );
+}
+
+/// Multiply two cartesian complex numbers, saturating on overflow.
+/// 
+/// Each product and sum saturates. If a product saturates, the result may differ from the exact result saturated.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(3) );
+/// let y = saturating_mul_cartesian( a, a );
+/// 
+/// // The product 3·3 saturates before the subtraction.
+/// assert_eq!{ y.re.to_num::<f32>(), -4.0 };
+/// assert_eq!{ y.im, F::MAX };
+/// ```
+pub fn saturating_mul_cartesian<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    let re = a.re.saturating_mul( b.re ).saturating_sub( a.im.saturating_mul( b.im ) );
+    let im = a.re.saturating_mul( b.im ).saturating_add( a.im.saturating_mul( b.re ) );
+    return Complex::new( re, im );
+}
+
+/// Multiply two cartesian complex numbers, wrapping on overflow.
+/// 
+/// Intermediate products which wrap make the result wrap, as in two's complement arithmetic.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(3) );
+/// let y = wrapping_mul_cartesian( a, a );
+/// assert_eq!{ y.re.to_num::<f32>(), -5.0 };
+/// assert_eq!{ y.im.to_num::<f32>(), -4.0 };
+/// ```
+pub fn wrapping_mul_cartesian<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    let re = a.re.wrapping_mul( b.re ).wrapping_sub( a.im.wrapping_mul( b.im ) );
+    let im = a.re.wrapping_mul( b.im ).wrapping_add( a.im.wrapping_mul( b.re ) );
+    return Complex::new( re, im );
+}
+
+/// Multiply a cartesian complex by a real scalar, returning `None` on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(3), F::from_num(-1) );
+/// assert_eq!{ checked_scale( a, F::from_num(3) ), None };
+/// assert_eq!{ checked_scale( a, F::from_num(-2) ).unwrap().im.to_num::<f32>(), 2.0 };
+/// ```
+pub fn checked_scale<T>( a: Complex<T>, b: T ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    return Some( Complex::new( a.re.checked_mul( b )?, a.im.checked_mul( b )? ) );
+}
+
+/// Multiply a cartesian complex by a real scalar, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(3), F::from_num(-1) );
+/// let y = saturating_scale( a, F::from_num(5) );
+/// assert_eq!{ y.re, F::MAX };
+/// assert_eq!{ y.im.to_num::<f32>(), -5.0 };
+/// ```
+pub fn saturating_scale<T>( a: Complex<T>, b: T ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.saturating_mul( b ), a.im.saturating_mul( b ) );
+}
+
+/// Multiply a cartesian complex by a real scalar, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(3), F::from_num(-1) );
+/// let y = wrapping_scale( a, F::from_num(5) );
+/// assert_eq!{ y.re.to_num::<f32>(), -1.0 };
+/// ```
+pub fn wrapping_scale<T>( a: Complex<T>, b: T ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    return Complex::new( a.re.wrapping_mul( b ), a.im.wrapping_mul( b ) );
+}
+
+/// Divide a cartesian complex by a real scalar, returning `None` on overflow or division by zero.
+/// c = a/b
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(-1) );
+/// assert_eq!{ checked_div_cartesian( a, F::from_num(0) ), None };
+/// assert_eq!{ checked_div_cartesian( a, F::from_num(0.25) ), None };
+/// assert_eq!{ checked_div_cartesian( a, F::from_num(2) ).unwrap().im.to_num::<f32>(), -0.5 };
+/// ```
+pub fn checked_div_cartesian<T>( a: Complex<T>, b: T ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    return Some( Complex::new( a.re.checked_div( b )?, a.im.checked_div( b )? ) );
+}
+
+/// Divide a cartesian complex by a real scalar, saturating on overflow.
+/// c = a/b
+/// 
+/// Division by zero saturates each part towards the sign of the numerator, see the [module documentation](self).
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(-1) );
+/// let y = saturating_div_cartesian( a, F::from_num(0) );
+/// assert_eq!{ y, Complex::new( F::MAX, F::MIN ) };
+/// ```
+pub fn saturating_div_cartesian<T>( a: Complex<T>, b: T ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    if b == T::from_num(0) {
+        return saturate_towards( a );
+    }
+    return Complex::new( a.re.saturating_div( b ), a.im.saturating_div( b ) );
+}
+
+/// Divide a cartesian complex by a real scalar, wrapping on overflow.
+/// c = a/b
+/// 
+/// Division by zero returns the maximum value of `T` in both parts, as [`div_cartesian`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(2), F::from_num(-1) );
+/// let y = wrapping_div_cartesian( a, F::from_num(0.25) );
+/// assert_eq!{ y.re.to_num::<f32>(), -8.0 };
+/// assert_eq!{ y.im.to_num::<f32>(), -4.0 };
+/// ```
+pub fn wrapping_div_cartesian<T>( a: Complex<T>, b: T ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    if b == T::from_num(0) {
+        return Complex::new( T::MAX, T::MAX );
+    }
+    return Complex::new( a.re.wrapping_div( b ), a.im.wrapping_div( b ) );
+}
+
+/// Divide two cartesian complex numbers, returning `None` on overflow or division by zero.
+/// c = a/b
+/// 
+/// Uses Smith's algorithm, see [`div`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(1), F::from_num(2) );
+/// let b = Complex::new( F::from_num(0.25), F::from_num(0) );
+/// assert_eq!{ checked_div( a, b ), None };
+/// assert_eq!{ checked_div( b, a ).unwrap().re.to_num::<f32>(), 0.049999997 };
+/// 
+/// let b = Complex::new( F::from_num(0), F::MIN );
+/// assert_eq!{ checked_div( a, b ).unwrap(), Complex::new( F::from_num(-0.25), F::from_num(0.125) ) };
+/// ```
+pub fn checked_div<T>( a: Complex<T>, b: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned
+{
+    if b.im.unsigned_abs() <= b.re.unsigned_abs()
+    {
+        let ratio = b.im.checked_div( b.re )?;
+        let denom = b.re.checked_add( b.im.checked_mul( ratio )? )?;
+        let re = a.re.checked_add( a.im.checked_mul( ratio )? )?.checked_div( denom )?;
+        let im = a.im.checked_sub( a.re.checked_mul( ratio )? )?.checked_div( denom )?;
+        return Some( Complex::new( re, im ) );
+    }
+    else
+    {
+        let ratio = b.re.checked_div( b.im )?;
+        let denom = b.re.checked_mul( ratio )?.checked_add( b.im )?;
+        let re = a.re.checked_mul( ratio )?.checked_add( a.im )?.checked_div( denom )?;
+        let im = a.im.checked_mul( ratio )?.checked_sub( a.re )?.checked_div( denom )?;
+        return Some( Complex::new( re, im ) );
+    }
+}
+
+/// Divide two cartesian complex numbers, saturating on overflow.
+/// c = a/b
+/// 
+/// Uses Smith's algorithm, see [`div`]. Each operation saturates.
+/// Division by zero saturates each part towards the sign of the numerator, see the [module documentation](self).
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(1), F::from_num(-3) );
+/// let b = Complex::new( F::from_num(0.25), F::from_num(0) );
+/// assert_eq!{ saturating_div( a, b ), Complex::new( F::from_num(4), F::MIN ) };
+/// 
+/// let b = Complex::new( F::from_num(0), F::from_num(0) );
+/// assert_eq!{ saturating_div( a, b ), Complex::new( F::MAX, F::MIN ) };
+/// ```
+pub fn saturating_div<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    let zero = T::from_num(0);
+    if b.re == zero && b.im == zero {
+        return saturate_towards( a );
+    }
+
+    if b.im.unsigned_abs() <= b.re.unsigned_abs()
+    {
+        let ratio = b.im.saturating_div( b.re );
+        let denom = b.re.saturating_add( b.im.saturating_mul( ratio ) );
+        let re = a.re.saturating_add( a.im.saturating_mul( ratio ) ).saturating_div( denom );
+        let im = a.im.saturating_sub( a.re.saturating_mul( ratio ) ).saturating_div( denom );
+        return Complex::new( re, im );
+    }
+    else
+    {
+        let ratio = b.re.saturating_div( b.im );
+        let denom = b.re.saturating_mul( ratio ).saturating_add( b.im );
+        let re = a.re.saturating_mul( ratio ).saturating_add( a.im ).saturating_div( denom );
+        let im = a.im.saturating_mul( ratio ).saturating_sub( a.re ).saturating_div( denom );
+        return Complex::new( re, im );
+    }
+}
+
+/// Divide two cartesian complex numbers, wrapping on overflow.
+/// c = a/b
+/// 
+/// Uses Smith's algorithm, see [`div`]. Each operation wraps.
+/// Division by zero returns the maximum value of `T` in both parts, as [`div`].
+/// The same applies when the denominator of Smith's algorithm wraps to zero, e.g. for b = (`T::MIN`, `T::MIN`).
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Complex::new( F::from_num(1), F::from_num(-3) );
+/// let b = Complex::new( F::from_num(0.25), F::from_num(0) );
+/// assert_eq!{ wrapping_div( a, b ), Complex::new( F::from_num(4), F::from_num(4) ) };
+/// 
+/// let b = Complex::new( F::from_num(0), F::MIN );
+/// assert_eq!{ wrapping_div( a, b ), Complex::new( F::from_num(0.375), F::from_num(0.125) ) };
+/// 
+/// let b = Complex::new( F::MIN, F::MIN );
+/// assert_eq!{ wrapping_div( a, b ), Complex::new( F::MAX, F::MAX ) };
+/// ```
+pub fn wrapping_div<T>( a: Complex<T>, b: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    let zero = T::from_num(0);
+    if b.re == zero && b.im == zero {
+        return Complex::new( T::MAX, T::MAX );
+    }
+
+    if b.im.unsigned_abs() <= b.re.unsigned_abs()
+    {
+        let ratio = b.im.wrapping_div( b.re );
+        let denom = b.re.wrapping_add( b.im.wrapping_mul( ratio ) );
+        if denom == zero {
+            return Complex::new( T::MAX, T::MAX );
+        }
+        let re = a.re.wrapping_add( a.im.wrapping_mul( ratio ) ).wrapping_div( denom );
+        let im = a.im.wrapping_sub( a.re.wrapping_mul( ratio ) ).wrapping_div( denom );
+        return Complex::new( re, im );
+    }
+    else
+    {
+        let ratio = b.re.wrapping_div( b.im );
+        let denom = b.re.wrapping_mul( ratio ).wrapping_add( b.im );
+        if denom == zero {
+            return Complex::new( T::MAX, T::MAX );
+        }
+        let re = a.re.wrapping_mul( ratio ).wrapping_add( a.im ).wrapping_div( denom );
+        let im = a.im.wrapping_mul( ratio ).wrapping_sub( a.re ).wrapping_div( denom );
+        return Complex::new( re, im );
+    }
+}
+
+/// The reciprocal of a cartesian complex, 1/a, returning `None` on overflow or division by zero.
+/// 
+/// Calculated with [`checked_div`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(0), F::from_num(4) );
+/// assert_eq!{ checked_recip( x ).unwrap(), Complex::new( F::from_num(0), F::from_num(-0.25) ) };
+/// 
+/// let x = Complex::new( F::from_num(0.0625), F::from_num(0) );
+/// assert_eq!{ checked_recip( x ), None };
+/// ```
+pub fn checked_recip<T>( a: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned
+{
+    let one = Complex::new( T::checked_from_num(1)?, T::from_num(0) );
+    return checked_div( one, a );
+}
+
+/// The reciprocal of a cartesian complex, 1/a, saturating on overflow.
+/// 
+/// Calculated with [`saturating_div`], and saturates in the same way when a is zero.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(0), F::from_num(0.0625) );
+/// assert_eq!{ saturating_recip( x ), Complex::new( F::from_num(0), F::MIN ) };
+/// 
+/// let x = Complex::new( F::from_num(0), F::from_num(0) );
+/// assert_eq!{ saturating_recip( x ), Complex::new( F::MAX, F::from_num(0) ) };
+/// ```
+pub fn saturating_recip<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    let one = Complex::new( T::saturating_from_num(1), T::from_num(0) );
+    return saturating_div( one, a );
+}
+
+/// The reciprocal of a cartesian complex, 1/a, wrapping on overflow.
+/// 
+/// Calculated with [`wrapping_div`], and returns the maximum value of `T` in both parts when a is zero.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(0.125), F::from_num(0) );
+/// assert_eq!{ wrapping_recip( x ), Complex::new( F::MIN, F::from_num(0) ) };
+/// ```
+pub fn wrapping_recip<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    let one = Complex::new( T::wrapping_from_num(1), T::from_num(0) );
+    return wrapping_div( one, a );
+}
+
+/// The squared magnitude of a cartesian complex, re²+im², returning `None` on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(-2) );
+/// assert_eq!{ checked_norm_sqr( x ).unwrap().to_num::<f32>(), 5.0 };
+/// 
+/// let x = Complex::new( F::from_num(3), F::from_num(-4) );
+/// assert_eq!{ checked_norm_sqr( x ), None };
+/// ```
+pub fn checked_norm_sqr<T>( a: Complex<T> ) -> Option<T>
+    where T: fixed::traits::Fixed
+{
+    return a.re.checked_mul( a.re )?.checked_add( a.im.checked_mul( a.im )? );
+}
+
+/// The squared magnitude of a cartesian complex, re²+im², saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(3), F::from_num(-4) );
+/// assert_eq!{ saturating_norm_sqr( x ), F::MAX };
+/// ```
+pub fn saturating_norm_sqr<T>( a: Complex<T> ) -> T
+    where T: fixed::traits::Fixed
+{
+    return a.re.saturating_mul( a.re ).saturating_add( a.im.saturating_mul( a.im ) );
+}
+
+/// The squared magnitude of a cartesian complex, re²+im², wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(3), F::from_num(-4) );
+/// assert_eq!{ wrapping_norm_sqr( x ).to_num::<f32>(), -7.0 };
+/// ```
+pub fn wrapping_norm_sqr<T>( a: Complex<T> ) -> T
+    where T: fixed::traits::Fixed
+{
+    return a.re.wrapping_mul( a.re ).wrapping_add( a.im.wrapping_mul( a.im ) );
+}
+
+/// The complex conjugate of a cartesian complex, returning `None` on overflow.
+/// 
+/// The negation overflows only for an imaginary part of `T::MIN`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(-2) );
+/// assert_eq!{ checked_conj( x ).unwrap(), Complex::new( F::from_num(1), F::from_num(2) ) };
+/// assert_eq!{ checked_conj( Complex::new( F::from_num(1), F::MIN ) ), None };
+/// ```
+pub fn checked_conj<T>( a: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned
+{
+    return Some( Complex::new( a.re, a.im.checked_neg()? ) );
+}
+
+/// The complex conjugate of a cartesian complex, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::MIN );
+/// assert_eq!{ saturating_conj( x ), Complex::new( F::from_num(1), F::MAX ) };
+/// ```
+pub fn saturating_conj<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.re, a.im.saturating_neg() );
+}
+
+/// The complex conjugate of a cartesian complex, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::MIN );
+/// assert_eq!{ wrapping_conj( x ), Complex::new( F::from_num(1), F::MIN ) };
+/// ```
+pub fn wrapping_conj<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.re, a.im.wrapping_neg() );
+}
+
+/// Multiply a cartesian complex by j, returning `None` on overflow.
+/// 
+/// The negation overflows only for an imaginary part of `T::MIN`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(-2) );
+/// assert_eq!{ checked_mul_j( x ).unwrap(), Complex::new( F::from_num(2), F::from_num(1) ) };
+/// assert_eq!{ checked_mul_j( Complex::new( F::from_num(1), F::MIN ) ), None };
+/// ```
+pub fn checked_mul_j<T>( a: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned
+{
+    return Some( Complex::new( a.im.checked_neg()?, a.re ) );
+}
+
+/// Multiply a cartesian complex by j, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::MIN );
+/// assert_eq!{ saturating_mul_j( x ), Complex::new( F::MAX, F::from_num(1) ) };
+/// ```
+pub fn saturating_mul_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.im.saturating_neg(), a.re );
+}
+
+/// Multiply a cartesian complex by j, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::MIN );
+/// assert_eq!{ wrapping_mul_j( x ), Complex::new( F::MIN, F::from_num(1) ) };
+/// ```
+pub fn wrapping_mul_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.im.wrapping_neg(), a.re );
+}
+
+/// Multiply a cartesian complex by -j, returning `None` on overflow.
+/// 
+/// The negation overflows only for a real part of `T::MIN`.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(-2) );
+/// assert_eq!{ checked_mul_neg_j( x ).unwrap(), Complex::new( F::from_num(-2), F::from_num(-1) ) };
+/// assert_eq!{ checked_mul_neg_j( Complex::new( F::MIN, F::from_num(1) ) ), None };
+/// ```
+pub fn checked_mul_neg_j<T>( a: Complex<T> ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned
+{
+    return Some( Complex::new( a.im, a.re.checked_neg()? ) );
+}
+
+/// Multiply a cartesian complex by -j, saturating on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::MIN, F::from_num(1) );
+/// assert_eq!{ saturating_mul_neg_j( x ), Complex::new( F::from_num(1), F::MAX ) };
+/// ```
+pub fn saturating_mul_neg_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.im, a.re.saturating_neg() );
+}
+
+/// Multiply a cartesian complex by -j, wrapping on overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::MIN, F::from_num(1) );
+/// assert_eq!{ wrapping_mul_neg_j( x ), Complex::new( F::from_num(1), F::MIN ) };
+/// ```
+pub fn wrapping_mul_neg_j<T>( a: Complex<T> ) -> Complex<T>
+    where T: fixed::traits::FixedSigned
+{
+    return Complex::new( a.im, a.re.wrapping_neg() );
+}
+
+/// Rase a complex number to an integer power, returning `None` on overflow.
+/// `base^power`.
+/// 
+/// Calculated by exponentiation by squaring with [`checked_mul_cartesian`], see [`powi`].
+/// Returns `None` if any of the intermediate products overflow.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(1) );
+/// assert_eq!{ checked_powi( x, 6 ).unwrap(), Complex::new( F::from_num(0), F::MIN ) };
+/// assert_eq!{ checked_powi( x, 7 ), None };
+/// ```
+pub fn checked_powi<T>( base: Complex<T>, power: usize ) -> Option<Complex<T>>
+    where T: fixed::traits::Fixed
+{
+    let mut n      = power;
+    let mut factor = base;
+    let mut result = Complex::new( T::checked_from_num(1)?, T::from_num(0) );
+
+    while 0 < n
+    {
+        if n & 1 == 1
+        {
+            result = checked_mul_cartesian( result, factor )?;
+        }
+        n >>= 1;
+        if 0 < n
+        {
+            factor = checked_mul_cartesian( factor, factor )?;
+        }
+    }
+    return Some( result );
+}
+
+/// Rase a complex number to an integer power, saturating on overflow.
+/// `base^power`.
+/// 
+/// Calculated by exponentiation by squaring with [`saturating_mul_cartesian`], see [`powi`].
+/// Each product saturates, so the result may differ from the exact result saturated.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(1) );
+/// assert_eq!{ saturating_powi( x, 7 ), Complex::new( F::MAX, F::MIN ) };
+/// ```
+pub fn saturating_powi<T>( base: Complex<T>, power: usize ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    let mut n      = power;
+    let mut factor = base;
+    let mut result = Complex::new( T::saturating_from_num(1), T::from_num(0) );
+
+    while 0 < n
+    {
+        if n & 1 == 1
+        {
+            result = saturating_mul_cartesian( result, factor );
+        }
+        n >>= 1;
+        if 0 < n
+        {
+            factor = saturating_mul_cartesian( factor, factor );
+        }
+    }
+    return result;
+}
+
+/// Rase a complex number to an integer power, wrapping on overflow.
+/// `base^power`.
+/// 
+/// Calculated by exponentiation by squaring with [`wrapping_mul_cartesian`], see [`powi`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(1) );
+/// assert_eq!{ wrapping_powi( x, 7 ), Complex::new( F::MIN, F::MIN ) };
+/// ```
+pub fn wrapping_powi<T>( base: Complex<T>, power: usize ) -> Complex<T>
+    where T: fixed::traits::Fixed
+{
+    let mut n      = power;
+    let mut factor = base;
+    let mut result = Complex::new( T::wrapping_from_num(1), T::from_num(0) );
+
+    while 0 < n
+    {
+        if n & 1 == 1
+        {
+            result = wrapping_mul_cartesian( result, factor );
+        }
+        n >>= 1;
+        if 0 < n
+        {
+            factor = wrapping_mul_cartesian( factor, factor );
+        }
+    }
+    return result;
+}
+
+/// Rase a complex number to an integer power through polar form, returning `None` if the magnitude overflows.
+/// `base^power`.
+/// 
+/// Calculated as [`powi_polar`], with the magnitude raised by [`Polar::checked_powi`].
+/// The magnitude of `base` saturates in [`to_polar`], see [`crate::hypot`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(1) );
+/// assert_eq!{ checked_powi_polar( x, 2 ).unwrap().im.to_num::<f32>(), 1.9999036 };
+/// assert_eq!{ checked_powi_polar( x, 7 ), None };
+/// ```
+pub fn checked_powi_polar<T>( base: Complex<T>, power: i32 ) -> Option<Complex<T>>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi + MixedTrigonometry
+{
+    return Some( to_cartsian( to_polar( base ).checked_powi( power )? ) );
+}
+
+/// Rase a complex number to an integer power through polar form, saturating the magnitude on overflow.
+/// `base^power`.
+/// 
+/// Calculated as [`powi_polar`], with the magnitude raised by [`Polar::saturating_powi`].
+/// The angle is kept, so a saturated result points in the direction of the exact result, within the accuracy of [`atan::atan2`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// use num::complex::Complex;
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let x = Complex::new( F::from_num(1), F::from_num(1) );
+/// let y = saturating_powi_polar( x, 7 );
+/// assert_eq!{ y.re.to_num::<f32>(), 5.8479147 };
+/// assert_eq!{ y.im.to_num::<f32>(), -5.4591107 };
+/// ```
+pub fn saturating_powi_polar<T>( base: Complex<T>, power: i32 ) -> Complex<T>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedAbs + MixedOps + MixedPowi + MixedReal + MixedPi + MixedTrigonometry
+{
+    return to_cartsian( to_polar( base ).saturating_powi( power ) );
+}
+
+/// Multiply fixed-point complex numbers in polar form, returning `None` if the magnitude overflows.
+/// 
+/// The resulting angle is wrapped with [`angle::wrap_phase`], as in [`mul_polar`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Polar::<F>::new( F::from_num(2), F::from_num(3) );
+/// let b = Polar::<F>::new( F::from_num(5), F::from_num(1) );
+/// assert_eq!{ checked_mul_polar( a, a ).unwrap().r.to_num::<f32>(), 4.0 };
+/// assert_eq!{ checked_mul_polar( a, b ), None };
+/// ```
+pub fn checked_mul_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Option<Polar<T, U>>
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let zero = T::from_num(0);
+    if a.r == zero || b.r == zero {
+        return Some( Polar::new( zero, zero ) );
+    }
+    return Some( Polar::new( a.r.checked_mul( b.r )?, angle::wrap_phase::<U, T>( a.theta+b.theta ) ) );
+}
+
+/// Multiply fixed-point complex numbers in polar form, saturating the magnitude on overflow.
+/// 
+/// The resulting angle is wrapped with [`angle::wrap_phase`], as in [`mul_polar`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Polar::<F>::new( F::from_num(2), F::from_num(3) );
+/// let b = Polar::<F>::new( F::from_num(5), F::from_num(1) );
+/// let c = saturating_mul_polar( a, b );
+/// assert_eq!{ c.r, F::MAX };
+/// assert_eq!{ c.theta.to_num::<f32>(), -2.2831852 };
+/// ```
+pub fn saturating_mul_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Polar<T, U>
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let zero = T::from_num(0);
+    if a.r == zero || b.r == zero {
+        return Polar::new( zero, zero );
+    }
+    return Polar::new( a.r.saturating_mul( b.r ), angle::wrap_phase::<U, T>( a.theta+b.theta ) );
+}
+
+/// Divide fixed-point complex numbers in polar form, returning `None` on overflow or division by zero.
+/// c = a/b
+/// 
+/// The resulting angle is wrapped with [`angle::wrap_phase`], as in [`div_polar`].
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Polar::<F>::new( F::from_num(2), F::from_num(3) );
+/// let b = Polar::<F>::new( F::from_num(0.25), F::from_num(1) );
+/// assert_eq!{ checked_div_polar( b, a ).unwrap().r.to_num::<f32>(), 0.125 };
+/// assert_eq!{ checked_div_polar( a, b ), None };
+/// ```
+pub fn checked_div_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Option<Polar<T, U>>
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    return Some( Polar::new( a.r.checked_div( b.r )?, angle::wrap_phase::<U, T>( a.theta-b.theta ) ) );
+}
+
+/// Divide fixed-point complex numbers in polar form, saturating the magnitude on overflow.
+/// c = a/b
+/// 
+/// The resulting angle is wrapped with [`angle::wrap_phase`], as in [`div_polar`].
+/// Division by zero saturates the magnitude towards the sign of the numerator, see the [module documentation](self).
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::complex::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// type F = FixedI32<U28>;
+/// 
+/// let a = Polar::<F>::new( F::from_num(2), F::from_num(3) );
+/// let b = Polar::<F>::new( F::from_num(0.25), F::from_num(1) );
+/// assert_eq!{ saturating_div_polar( a, b ).r, F::MAX };
+/// 
+/// let b = Polar::<F>::new( F::from_num(0), F::from_num(0) );
+/// assert_eq!{ saturating_div_polar( a, b ).r, F::MAX };
+/// ```
+pub fn saturating_div_polar<T, U>( a: Polar<T, U>, b: Polar<T, U> ) -> Polar<T, U>
+    where U: AngleUnit,
+          T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let r: T;
+    if b.r == T::from_num(0) {
+        r = saturate_sign( a.r );
+    } else {
+        r = a.r.saturating_div( b.r );
+    }
+    return Polar::new( r, angle::wrap_phase::<U, T>( a.theta-b.theta ) );
+}
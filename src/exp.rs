@@ -0,0 +1,163 @@
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+use fixed::types::extra::U30;
+use fixed::FixedI32;
+
+use crate::log::Wide;
+
+/// Fractional type for the polynomial evaluation.
+type Frac = FixedI32<U30>;
+
+/// Coefficients c1, c2, .. of the minimax polynomial for 2^f-1 in the 0=<f<1 range.
+/// Max error 2.0*10⁻⁹ relative to 2^f.
+const EXP2_COEFFICIENTS: [f64; 6] = [ 0.6931470444433243, 0.2402293055522314, 0.05548528061865165,
+                                      0.009675451566895987, 0.001246784644639788, 0.00021612914953285476 ];
+
+/// Exponents beyond this limit are out of range for all fixed-point types.
+const MAX_EXPONENT: i64 = 256;
+
+/// Calculate 2^x for an exponent in the wide type, rounded and saturated to `T`.
+///
+/// x is split into x = k + f, where 0=<f<1, so that 2^x = 2^k·2^f.
+fn exp2_wide<T>( x: Wide ) -> T
+    where T: fixed::traits::Fixed
+{
+    // The arithmetic shift rounds towards -∞, so that the remainder is positive.
+    let k = x.to_bits() >> 32;
+    let f = Frac::from_bits( ((x.to_bits() as u32) >> 2) as i32 );
+
+    if MAX_EXPONENT < k {
+        return T::MAX;
+    } else if k < -MAX_EXPONENT {
+        return T::from_num(0);
+    }
+
+    let mut y = Frac::from_num( EXP2_COEFFICIENTS[EXP2_COEFFICIENTS.len()-1] );
+    for c in EXP2_COEFFICIENTS.iter().rev().skip(1)
+    {
+        y = y*f + Frac::from_num( *c );
+    }
+    y = y*f;
+
+    // 1 =< 2^f < 2 in Q30, scaled by 2^k.
+    return crate::from_bits_scaled( (1i128 << 30) + y.to_bits() as i128, 30, k as i32 );
+}
+
+/// Calculate 2^x using argument reduction and a minimax polynomial.
+///
+/// x is split into x = k + f, where 0=<f<1, so that 2^x = 2^k·2^f.
+/// 2^f is approximated by a sixth order minimax polynomial, accurate to 2.0*10⁻⁹ relative to the result.
+/// The accuracy is further limited by the resolution of `T`.
+///
+/// The result saturates to the maximum value of `T` on overflow, and is zero below the resolution of `T`.
+///
+/// ## Arguments
+///
+/// * `x` - The exponent.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = exp::exp2( FixedI32::<U16>::from_num(3.5) );
+/// assert_eq!{ y.to_num::<f32>(), 11.313705 };
+///
+/// let y = exp::exp2( FixedI32::<U16>::from_num(-3) );
+/// assert_eq!{ y.to_num::<f32>(), 0.125 };
+///
+/// let y = exp::exp2( FixedI32::<U16>::from_num(16) );
+/// assert_eq!{ y, FixedI32::<U16>::MAX };
+/// ```
+pub fn exp2<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return exp2_wide( Wide::saturating_from_num(x) );
+}
+
+/// Calculate e^x using argument reduction and a minimax polynomial.
+///
+/// Calculated as e^x = 2^(x·log2(e)), see [`exp2`].
+/// Accurate to about 2.0*10⁻⁹ relative to the result, limited by the resolution of `T`.
+///
+/// The result saturates to the maximum value of `T` on overflow, and is zero below the resolution of `T`.
+///
+/// ## Arguments
+///
+/// * `x` - The exponent.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = exp::exp( FixedI32::<U16>::from_num(2.5) );
+/// assert_eq!{ y.to_num::<f32>(), 12.182495 };
+///
+/// let y = exp::exp( FixedI32::<U16>::from_num(-4) );
+/// assert_eq!{ y.to_num::<f32>(), 0.018310547 };
+/// ```
+pub fn exp<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    let exponent = Wide::saturating_from_num(x).saturating_mul( Wide::from_num( fixed::consts::LOG2_E ) );
+    return exp2_wide( exponent );
+}
+
+/// Calculate x^y for a real exponent y.
+///
+/// Calculated as x^y = 2^(y·log2(x)), using [`crate::log::log2`] and [`exp2`] internally in a wide type.
+/// The error of log2(x) is amplified by y, so the relative error is about ln(2)·|y|·4.7*10⁻⁸, limited by the resolution of `T`.
+///
+/// The result saturates to the maximum value of `T` on overflow, and is zero below the resolution of `T`.
+/// 0^y is zero for positive y, one for y=0 and saturates for negative y.
+/// Negative bases are outside the domain of the function, and return zero.
+///
+/// ## Arguments
+///
+/// * `x` - The base.
+/// * `y` - The exponent.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let x = FixedI32::<U16>::from_num(0.5);
+/// let y = exp::powf( x, FixedI32::<U16>::from_num(2.2) );
+/// assert_eq!{ y.to_num::<f32>(), 0.21763611 };
+///
+/// let y = exp::powf( FixedI32::<U16>::from_num(9), FixedI32::<U16>::from_num(0.5) );
+/// assert_eq!{ y.to_num::<f32>(), 3.0 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of gamma correction with powf, compared to the `std::f64::powf` implementation.
+///
+/// The comparison is done for U28 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/powf_error.png?raw=true)
+///
+pub fn powf<T>( x: T, y: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    let zero = T::from_num(0);
+
+    if x < zero {
+        return zero;
+    } else if x == zero {
+        if zero < y {
+            return zero;
+        } else if y == zero {
+            return T::saturating_from_num(1);
+        }
+        return T::MAX;
+    }
+
+    let exponent = crate::log::log2_wide(x).saturating_mul( Wide::saturating_from_num(y) );
+    return exp2_wide( exponent );
+}
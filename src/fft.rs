@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return, clippy::assign_op_pattern, clippy::needless_late_init)]
+
 extern crate alloc;
 use alloc::vec::Vec;
 
@@ -59,6 +61,7 @@ pub fn log2( x: usize ) -> usize
 /// 
 /// * `arr` - A mutable reference to the array to do the computation on, and store the result in.
 /// 
+#[allow(clippy::manual_swap)]
 fn bitreverse_order<T>( arr: &mut [Complex<T>] )
     where T: core::marker::Copy
 {
@@ -163,6 +166,7 @@ pub fn fft<T>( array: &mut [Complex<T>] )
 ///                         Complex::<F<U>>::new(F::<U>::from_num(1),             F::<U>::from_num(0)      ),
 ///                         Complex::<F<U>>::new(F::<U>::from_num(0.000000015,),  F::<U>::from_num(-1.000000004) )] );
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn ifft<T>( vec: &mut Vec<Complex<T>> )
     where T: MixedNum + MixedNumSigned + MixedTrigonometry + MixedSqrt + MixedNumConversion<i32> + MixedReal + MixedOps + MixedPi + MixedOne
 {
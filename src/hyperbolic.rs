@@ -0,0 +1,389 @@
+//! Fixed-point CORDIC in hyperbolic mode.
+//!
+//! Rotation mode yields cosh and sinh, from which exp, sinh, cosh and tanh are found after range reduction.
+//! Vectoring mode yields atanh of a ratio, from which ln and log2 are found after normalization.
+//!
+//! The iterations run in a 64-bit intermediate type with 55 fractional bits, so that truncation in the
+//! iterations does not limit the accuracy of the result. Results are saturated to the range of the argument type.
+
+#![allow(clippy::needless_return, clippy::needless_late_init)]
+
+use fixed::types::extra::U55;
+use fixed::FixedI64;
+
+/// The maximum number of CORDIC iterations. Larger iteration counts are clamped to this value.
+pub const MAX_ITERATIONS: usize = 48;
+
+/// Wide intermediate type for the CORDIC iterations and the range reduction.
+type Wide = FixedI64<U55>;
+
+/// The shift of each hyperbolic CORDIC iteration.
+/// Iterations 4, 13 and 40 are repeated to ensure convergence.
+const SHIFT_SEQUENCE: [u32; MAX_ITERATIONS] = [ 1, 2, 3, 4, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 40, 41, 42, 43, 44, 45 ];
+
+/// The elementary angles atanh(2⁻ⁱ) for each shift in the sequence, in Q9.55 format.
+const ATANH_TABLE: [i64; MAX_ITERATIONS] = [
+    0x00464FA9EAB40C2A, 0x0020B15DF50228A3, 0x001015891C9EAEF7, 0x000802AC4569BAD6,
+    0x000802AC4569BAD6, 0x0004005562246BB9, 0x0002000AAB1115A4, 0x0001000155588892,
+    0x000080002AAAC444, 0x0000400005555622, 0x0000200000AAAAB1, 0x0000100000155556,
+    0x000008000002AAAB, 0x0000040000005555, 0x0000040000005555, 0x0000020000000AAB,
+    0x0000010000000155, 0x000000800000002B, 0x0000004000000005, 0x0000002000000001,
+    0x0000001000000000, 0x0000000800000000, 0x0000000400000000, 0x0000000200000000,
+    0x0000000100000000, 0x0000000080000000, 0x0000000040000000, 0x0000000020000000,
+    0x0000000010000000, 0x0000000008000000, 0x0000000004000000, 0x0000000002000000,
+    0x0000000001000000, 0x0000000000800000, 0x0000000000400000, 0x0000000000200000,
+    0x0000000000100000, 0x0000000000080000, 0x0000000000040000, 0x0000000000020000,
+    0x0000000000010000, 0x0000000000008000, 0x0000000000008000, 0x0000000000004000,
+    0x0000000000002000, 0x0000000000001000, 0x0000000000000800, 0x0000000000000400,
+];
+
+/// The inverse hyperbolic CORDIC gain 1/K after n iterations, stored at index n-1, in Q9.55 format.
+const INVERSE_GAIN: [i64; MAX_ITERATIONS] = [
+    0x0093CD3A2C8198E2, 0x0098A61EC954F486, 0x0099DB0B02F09D2E, 0x009A28326AD4E1EC,
+    0x009A75808374113F, 0x009A88D2D306BFFC, 0x009A8DA75393AC18, 0x009A8EDC7281B13E,
+    0x009A8F29BA29E042, 0x009A8F3D0C12B6E3, 0x009A8F41E08CD939, 0x009A8F4315AB609A,
+    0x009A8F4362F3025E, 0x009A8F437644EACE, 0x009A8F438996D341, 0x009A8F438E6B4D5D,
+    0x009A8F438FA06BE5, 0x009A8F438FEDB386, 0x009A8F439001056F, 0x009A8F439005D9E9,
+    0x009A8F4390070F07, 0x009A8F4390075C4F, 0x009A8F4390076FA1, 0x009A8F4390077475,
+    0x009A8F43900775AB, 0x009A8F43900775F8, 0x009A8F439007760B, 0x009A8F4390077610,
+    0x009A8F4390077611, 0x009A8F4390077611, 0x009A8F4390077612, 0x009A8F4390077612,
+    0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612,
+    0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612,
+    0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612,
+    0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612, 0x009A8F4390077612,
+];
+
+/// Hyperbolic CORDIC in rotation mode.
+///
+/// Returns (cosh(z), sinh(z)). Converges for |z| < 1.118.
+fn rotation( z: Wide, iterations: usize ) -> (Wide, Wide)
+{
+    let n = iterations.min( MAX_ITERATIONS );
+
+    let mut x: Wide;
+    if n == 0 {
+        x = Wide::from_num(1);
+    } else {
+        x = Wide::from_bits( INVERSE_GAIN[n-1] );
+    }
+    let mut y  = Wide::from_num(0);
+    let mut z_ = z;
+
+    for (shift, atanh) in SHIFT_SEQUENCE.iter().zip( ATANH_TABLE.iter() ).take(n)
+    {
+        let x_shift = x >> *shift;
+        let y_shift = y >> *shift;
+        let angle   = Wide::from_bits( *atanh );
+
+        if z_.is_negative() {
+            x  -= y_shift;
+            y  -= x_shift;
+            z_ += angle;
+        } else {
+            x  += y_shift;
+            y  += x_shift;
+            z_ -= angle;
+        }
+    }
+    return (x, y);
+}
+
+/// Hyperbolic CORDIC in vectoring mode.
+///
+/// Returns atanh(y/x) for x>0. Converges for |atanh(y/x)| < 1.118.
+fn vectoring( x: Wide, y: Wide, iterations: usize ) -> Wide
+{
+    let n = iterations.min( MAX_ITERATIONS );
+
+    let mut x_ = x;
+    let mut y_ = y;
+    let mut z  = Wide::from_num(0);
+
+    for (shift, atanh) in SHIFT_SEQUENCE.iter().zip( ATANH_TABLE.iter() ).take(n)
+    {
+        let x_shift = x_ >> *shift;
+        let y_shift = y_ >> *shift;
+        let angle   = Wide::from_bits( *atanh );
+
+        if y_.is_negative() {
+            x_ += y_shift;
+            y_ += x_shift;
+            z  -= angle;
+        } else {
+            x_ -= y_shift;
+            y_ -= x_shift;
+            z  += angle;
+        }
+    }
+    return z;
+}
+
+/// Reduce x = k·ln(2) + r, where 0=<r<ln(2).
+///
+/// The argument is limited to |x|=<128, beyond which all results of this module saturate for 64-bit types.
+///
+/// Returns the tuple (k, r).
+fn reduce<T>( x: T ) -> (i32, Wide)
+    where T: fixed::traits::FixedSigned
+{
+    let limit  = Wide::from_num(128);
+    let x_wide = Wide::saturating_from_num( x ).clamp( -limit, limit );
+
+    let k = (x_wide*Wide::from_num( fixed::consts::LOG2_E )).floor().to_num::<i32>();
+    let r = x_wide - Wide::from_num(k)*Wide::from_num( fixed::consts::LN_2 );
+    return (k, r);
+}
+
+/// Convert y·2^k to `T`, rounded to nearest and saturating on overflow.
+fn scale<T>( y: Wide, k: i32 ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    return crate::from_bits_scaled( y.to_bits() as i128, 55, k );
+}
+
+/// Calculate e^x using CORDIC in hyperbolic mode.
+///
+/// The argument is reduced to x = k·ln(2) + r, where 0=<r<ln(2), so that e^x = 2^k·e^r.
+/// The result saturates to the maximum value of `T` on overflow, and is zero below the resolution of `T`.
+///
+/// Accurate to about 2⁻ⁿ relative to the result for n iterations, limited by the resolution of `T`.
+///
+/// ## Arguments
+///
+/// * `x`          - The exponent.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::exp( FixedI32::<U16>::from_num(2.5), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 12.182495 };
+///
+/// let y = hyperbolic::exp( FixedI32::<U16>::from_num(-4), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 0.018310547 };
+///
+/// let y = hyperbolic::exp( FixedI32::<U16>::from_num(20), 24 );
+/// assert_eq!{ y, FixedI32::<U16>::MAX };
+/// ```
+pub fn exp<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    let (k, r) = reduce( x );
+    let (cosh, sinh) = rotation( r, iterations );
+    return scale( cosh + sinh, k );
+}
+
+/// Calculate e^|x|·2^-k and e^-|x|·2^-k, where |x| = k·ln(2) + r.
+///
+/// Returns the tuple (e^r, e^-r·2^-2k, k).
+fn exp_pair<T>( x: T, iterations: usize ) -> (Wide, Wide, i32)
+    where T: fixed::traits::FixedSigned
+{
+    let (k, r) = reduce( x.saturating_abs() );
+    let (cosh, sinh) = rotation( r, iterations );
+
+    let e_neg = (cosh - sinh).checked_shr( 2*k as u32 ).unwrap_or( Wide::from_num(0) );
+    return (cosh + sinh, e_neg, k);
+}
+
+/// Calculate sinh(x) using CORDIC in hyperbolic mode.
+///
+/// sinh is found from (e^|x|-e^-|x|)/2, with both exponentials from the same CORDIC rotation after range reduction.
+/// The result saturates on overflow.
+///
+/// ## Arguments
+///
+/// * `x`          - The function argument.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::sinh( FixedI32::<U16>::from_num(0.5), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 0.5211029 };
+///
+/// let y = hyperbolic::sinh( FixedI32::<U16>::from_num(-3), 24 );
+/// assert_eq!{ y.to_num::<f32>(), -10.017868 };
+/// ```
+pub fn sinh<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    let (e_pos, e_neg, k) = exp_pair( x, iterations );
+
+    let y: T = scale( e_pos - e_neg, k-1 );
+    if x.is_negative() {
+        return -y;
+    }
+    return y;
+}
+
+/// Calculate cosh(x) using CORDIC in hyperbolic mode.
+///
+/// cosh is found from (e^|x|+e^-|x|)/2, with both exponentials from the same CORDIC rotation after range reduction.
+/// The result saturates on overflow.
+///
+/// ## Arguments
+///
+/// * `x`          - The function argument.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::cosh( FixedI32::<U16>::from_num(0.5), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 1.1276245 };
+///
+/// let y = hyperbolic::cosh( FixedI32::<U16>::from_num(-3), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 10.067657 };
+/// ```
+pub fn cosh<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    let (e_pos, e_neg, k) = exp_pair( x, iterations );
+    return scale( e_pos + e_neg, k-1 );
+}
+
+/// Calculate tanh(x) using CORDIC in hyperbolic mode.
+///
+/// tanh is found from (e^|x|-e^-|x|)/(e^|x|+e^-|x|), with both exponentials from the same CORDIC rotation after range reduction.
+/// Both terms are scaled by 2^-k, so tanh can not overflow, and is valid for the entire range of `T`.
+///
+/// ## Arguments
+///
+/// * `x`          - The function argument.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::tanh( FixedI32::<U16>::from_num(0.5), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 0.46211243 };
+///
+/// let y = hyperbolic::tanh( FixedI32::<U16>::from_num(-30000), 24 );
+/// assert_eq!{ y.to_num::<f32>(), -1.0 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the comparison between the CORDIC tanh with 24 iterations, and the `std::f32::tanh` implementation.
+///
+/// The comparison is done for U16 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/tanh_comparison.png?raw=true)
+///
+pub fn tanh<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    let (e_pos, e_neg, _k) = exp_pair( x, iterations );
+
+    let y: T = scale( (e_pos - e_neg)/(e_pos + e_neg), 0 );
+    if x.is_negative() {
+        return -y;
+    }
+    return y;
+}
+
+/// Calculate ln(x) using CORDIC in hyperbolic vectoring mode.
+///
+/// The argument is normalized to x = m·2^k, where 0.5=<m<1, so that ln(x) = 2·atanh((m-1)/(m+1)) + k·ln(2).
+/// Non-positive arguments, and results out of range of `T`, saturate.
+///
+/// Accurate to about 2⁻ⁿ for n iterations, limited by the resolution of `T`.
+///
+/// ## Arguments
+///
+/// * `x`          - The function argument.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::ln( FixedI32::<U16>::from_num(10), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 2.3025818 };
+///
+/// let y = hyperbolic::ln( FixedI32::<U16>::from_num(0), 24 );
+/// assert_eq!{ y, FixedI32::<U16>::MIN };
+/// ```
+pub fn ln<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    if x <= T::from_num(0) {
+        return T::MIN;
+    }
+    let (atanh, exponent) = normalized_atanh( x, iterations );
+
+    let y = atanh*Wide::from_num(2) + Wide::from_num(exponent)*Wide::from_num( fixed::consts::LN_2 );
+    return scale( y, 0 );
+}
+
+/// Calculate log2(x) using CORDIC in hyperbolic vectoring mode.
+///
+/// The argument is normalized to x = m·2^k, where 0.5=<m<1, so that log2(x) = 2·atanh((m-1)/(m+1))·log2(e) + k.
+/// Non-positive arguments, and results out of range of `T`, saturate.
+///
+/// ## Arguments
+///
+/// * `x`          - The function argument.
+/// * `iterations` - The number of CORDIC iterations, including the repeated iterations.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = hyperbolic::log2( FixedI32::<U16>::from_num(10), 24 );
+/// assert_eq!{ y.to_num::<f32>(), 3.32193 };
+///
+/// let y = hyperbolic::log2( FixedI32::<U16>::from_num(0.125), 24 );
+/// assert_eq!{ y.to_num::<f32>(), -3.0 };
+/// ```
+pub fn log2<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::FixedSigned
+{
+    if x <= T::from_num(0) {
+        return T::MIN;
+    }
+    let (atanh, exponent) = normalized_atanh( x, iterations );
+
+    let y = atanh*Wide::from_num(2)*Wide::from_num( fixed::consts::LOG2_E ) + Wide::from_num(exponent);
+    return scale( y, 0 );
+}
+
+/// Normalize x = m·2^k, where 0.5=<m<1, and calculate atanh((m-1)/(m+1)).
+///
+/// Returns the tuple (atanh((m-1)/(m+1)), k). x must be positive.
+fn normalized_atanh<T>( x: T, iterations: usize ) -> (Wide, i32)
+    where T: fixed::traits::FixedSigned
+{
+    let exponent = x.int_log2() + 1;
+
+    // Normalize on the raw bits, so that no precision is lost for types with few fractional bits.
+    let bits: i128 = x.to_bits().try_into().unwrap_or(0);
+    let shift = 55 - T::FRAC_NBITS as i32 - exponent;
+
+    let m_wide: Wide;
+    if 0 <= shift {
+        m_wide = Wide::from_bits( (bits << shift as u32) as i64 );
+    } else {
+        m_wide = Wide::from_bits( (bits >> (-shift) as u32) as i64 );
+    }
+
+    let atanh  = vectoring( m_wide + Wide::from_num(1), m_wide - Wide::from_num(1), iterations );
+    return (atanh, exponent);
+}
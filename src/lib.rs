@@ -24,18 +24,33 @@
 #[cfg(all(not(feature = "alloc"), test))]
 extern crate std;
 
-use fixed;
 use mixed_num::traits::*;
 
+pub mod angle;
 pub mod atan;
+pub mod circular;
 pub mod complex;
+pub mod exp;
 #[cfg(feature = "alloc")]
 pub mod fft;
+pub mod hyperbolic;
+pub mod log;
+pub mod lut;
+pub mod magnitude;
+pub mod minimax;
+pub mod phase;
+pub mod root;
 pub mod sqrt;
 
 /// Rase fixed number to an integer-valued power.
 /// `base^power`.
 ///
+/// Calculated by exponentiation by squaring, using O(log n) multiplications.
+/// Negative powers are calculated as the positive power of the reciprocal 1/base,
+/// so that the intermediate values do not overflow when the result is representable.
+///
+/// The function overflows like the underlying multiplication, see [`checked_powi`] and [`saturating_powi`] for fixed-point types.
+///
 /// ## Arguments
 ///
 /// * `base`  - The base number.
@@ -53,20 +68,241 @@ pub mod sqrt;
 ///
 /// let y = powi(x, 0);
 /// assert_eq!{ y.to_num::<f32>(), 1.0 };
+///
+/// let y = powi(x, -3);
+/// assert_eq!{ y.to_num::<f32>(), -0.125 };
 /// ```
-pub fn powi<T>(base: T, power: usize) -> T
+#[allow(clippy::needless_return)]
+pub fn powi<T>(base: T, power: i32) -> T
 where
     T: MixedNum + MixedNumConversion<i32> + MixedOps,
 {
-    if power == 0 {
-        return T::mixed_from_num(1i32);
+    let mut n = power.unsigned_abs();
+    let mut factor = base;
+    if power < 0 {
+        factor = T::mixed_from_num(1i32) / base;
+    }
+
+    // The product is kept as None until the first factor, to avoid multiplying by one.
+    let mut product: Option<T> = None;
+    while 0 < n {
+        if n & 1 == 1 {
+            product = match product {
+                None    => Some(factor),
+                Some(p) => Some(p * factor),
+            };
+        }
+        n >>= 1;
+        if 0 < n {
+            factor = factor * factor;
+        }
+    }
+
+    return match product {
+        None    => T::mixed_from_num(1i32),
+        Some(p) => p,
+    };
+}
+
+/// Rase fixed number to an integer-valued power, returning `None` on overflow.
+/// `base^power`.
+///
+/// Calculated by exponentiation by squaring, see [`powi`].
+/// Negative powers are calculated as the positive power of the reciprocal 1/base.
+///
+/// Returns `None` if the result overflows, or for zero raised to a negative power.
+///
+/// ## Arguments
+///
+/// * `base`  - The base number.
+/// * `power` - The power to raise 'base' to.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let x = FixedI32::<U16>::from_num(3);
+/// assert_eq!{ checked_powi(x, 9).unwrap().to_num::<f32>(), 19683.0 };
+/// assert_eq!{ checked_powi(x, 10), None };
+///
+/// let x = FixedI32::<U16>::from_num(0);
+/// assert_eq!{ checked_powi(x, -1), None };
+/// ```
+///
+/// The positive power of the base does not have to be representable for negative powers.
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let x = FixedI32::<U28>::from_num(2);
+/// assert_eq!{ checked_powi(x, -3).unwrap().to_num::<f32>(), 0.125 };
+///
+/// let x = FixedI32::<U28>::from_num(3);
+/// assert_eq!{ checked_powi(x, -2).unwrap().to_num::<f32>(), 0.111111104 };
+///
+/// let x = FixedI32::<U28>::from_num(0.1);
+/// assert_eq!{ checked_powi(x, -1), None };
+/// ```
+#[allow(clippy::needless_return)]
+pub fn checked_powi<T>(base: T, power: i32) -> Option<T>
+where
+    T: fixed::traits::Fixed,
+{
+    let mut n = power.unsigned_abs();
+    let mut factor = base;
+    if power < 0 {
+        factor = T::checked_from_num(1)?.checked_div(base)?;
+    }
+
+    let mut product: Option<T> = None;
+    while 0 < n {
+        if n & 1 == 1 {
+            product = match product {
+                None    => Some(factor),
+                Some(p) => Some(p.checked_mul(factor)?),
+            };
+        }
+        n >>= 1;
+        if 0 < n {
+            factor = factor.checked_mul(factor)?;
+        }
+    }
+
+    return match product {
+        None    => T::checked_from_num(1),
+        Some(p) => Some(p),
+    };
+}
+
+/// Rase fixed number to an integer-valued power, saturating on overflow.
+/// `base^power`.
+///
+/// Calculated by exponentiation by squaring, see [`checked_powi`].
+/// Overflowing results saturate to the minimum value of `T` for negative results, and to the maximum value otherwise.
+/// Zero raised to a negative power saturates to the maximum value.
+///
+/// ## Arguments
+///
+/// * `base`  - The base number.
+/// * `power` - The power to raise 'base' to.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let x = FixedI32::<U16>::from_num(-3);
+/// assert_eq!{ saturating_powi(x, 11), FixedI32::<U16>::MIN };
+/// assert_eq!{ saturating_powi(x, 10), FixedI32::<U16>::MAX };
+/// assert_eq!{ saturating_powi(x, -2).to_num::<f32>(), 0.11109924 };
+///
+/// let x = FixedI32::<U16>::from_num(-0.0001);
+/// assert_eq!{ saturating_powi(x, -3), FixedI32::<U16>::MIN };
+/// assert_eq!{ saturating_powi(x, -2), FixedI32::<U16>::MAX };
+/// ```
+///
+/// For high-fraction types, results below one are still calculated for bases above the range of the positive power.
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let x = FixedI32::<U28>::from_num(3);
+/// assert_eq!{ saturating_powi(x, -2).to_num::<f32>(), 0.111111104 };
+/// assert_eq!{ saturating_powi(x, 2), FixedI32::<U28>::MAX };
+/// ```
+#[allow(clippy::needless_return)]
+pub fn saturating_powi<T>(base: T, power: i32) -> T
+where
+    T: fixed::traits::Fixed,
+{
+    match checked_powi(base, power)
+    {
+        Some(y) => return y,
+        None    => {
+            if base < T::from_num(0) && power & 1 == 1 {
+                return T::MIN;
+            }
+            return T::MAX;
+        }
+    }
+}
+
+/// Calculate the hypotenuse sqrt(x²+y²) without intermediate overflow.
+///
+/// The arguments are pre-scaled by the larger magnitude a, with b being the smaller magnitude, so that
+/// `hypot = a·sqrt(1+(b/a)²) = a + a·(2s-1)`, where `s = sqrt(0.25+(b/a)²/4)`.
+/// All intermediate values are in the 0 =< x =< 1 range, so the result is valid over the full representable range of `T`.
+/// Results above the maximum value of `T` saturate.
+///
+/// For fixed-point types, the magnitude and angle can also be found together with CORDIC in vectoring mode, see [`circular::atan2_magnitude`].
+///
+/// ## Arguments
+///
+/// * `x` - The first side.
+/// * `y` - The second side.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let x = FixedI32::<U28>::from_num(3);
+/// let y = FixedI32::<U28>::from_num(-4);
+/// assert_eq!{ hypot(x, y).to_num::<f32>(), 5.0 };
+///
+/// let x = FixedI32::<U28>::from_num(6);
+/// assert_eq!{ hypot(x, x), FixedI32::<U28>::MAX };
+/// assert_eq!{ hypot(FixedI32::<U28>::MIN, x), FixedI32::<U28>::MAX };
+///
+/// assert_eq!{ hypot(5f32, 12f32), 13f32 };
+/// ```
+#[allow(clippy::needless_return)]
+pub fn hypot<T>(x: T, y: T) -> T
+where
+    T: MixedNum + MixedOps + MixedAbs + MixedReal,
+{
+    let zero = T::mixed_from_num(0i32);
+
+    // The magnitude of the minimum value of a signed type is above the maximum value, and can not be negated.
+    let min = T::mixed_min_value();
+    if min < zero && (x == min || y == min) {
+        return T::mixed_max_value();
     }
 
-    let mut temp: T = base;
-    for _i in 0..power - 1 {
-        temp = temp * base;
+    let x_abs = x.mixed_abs();
+    let y_abs = y.mixed_abs();
+
+    let a: T;
+    let b: T;
+    if y_abs < x_abs {
+        a = x_abs;
+        b = y_abs;
+    } else {
+        a = y_abs;
+        b = x_abs;
     }
-    return temp;
+
+    if a == zero {
+        return zero;
+    }
+
+    // s = sqrt(1+ratio²)/2, without constants above one.
+    let half_ratio = (b / a) * T::mixed_from_num(0.5);
+    let s = sqrt::mixed_newton(T::mixed_from_num(0.25) + half_ratio * half_ratio);
+
+    let half = T::mixed_from_num(0.5);
+    let increment = a * ((s - half) + (s - half));
+
+    if T::mixed_max_value() - increment < a {
+        return T::mixed_max_value();
+    }
+    return a + increment;
 }
 
 /// Get the sign of the argument with a unit value.
@@ -90,6 +326,7 @@ where
 /// y = sign(x);
 /// assert_eq!{ y.to_num::<f32>(), 1.0 };
 /// ```
+#[allow(clippy::needless_return)]
 pub fn sign<T>(x: T) -> T
 where
     T: fixed::traits::FixedSigned,
@@ -146,6 +383,7 @@ where
 ///
 /// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/cordic_poly_sine_error_comparison.png?raw=true)
 ///
+#[allow(clippy::needless_return)]
 pub fn sin<T>(x: T) -> T
 where
     T: fixed::traits::FixedSigned + MixedNum + MixedNumConversion<i32> + MixedOps,
@@ -211,6 +449,7 @@ where
 ///
 /// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/cordic_poly_cos_error_comparison.png?raw=true)
 ///
+#[allow(clippy::needless_return)]
 pub fn cos<T>(x: T) -> T
 where
     T: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi,
@@ -225,6 +464,9 @@ where
 
 /// Wrapps θ to the -π=<x<π range.
 ///
+/// Runs in constant time, independent of the argument.
+/// See [`angle::wrap_phase`] for other angle units.
+///
 /// ## Arguments
 ///
 /// * `phi` - The unwrapped phase in radians.
@@ -239,20 +481,75 @@ where
 /// let wrapped_phi = wrap_phase(phi);
 /// assert_eq!{ wrapped_phi.to_num::<f32>(), -0.2831853 };
 /// ```
+#[allow(clippy::needless_return, clippy::assign_op_pattern)]
 pub fn wrap_phase<T>(phi: T) -> T
 where
     T: MixedNum + MixedNumSigned + MixedNumConversion<i32> + MixedOps + MixedPi,
 {
-    let mixed_pi = T::mixed_pi();
-    let tau = T::mixed_from_num(2) * mixed_pi;
+    return angle::wrap_phase::<angle::Radians, T>(phi);
+}
 
-    let mut temp_scalar = phi;
+/// Wrapps θ to the 0=<x<2π range.
+///
+/// Runs in constant time, independent of the argument.
+/// See [`angle::wrap_phase_positive`] for other angle units.
+///
+/// ## Arguments
+///
+/// * `phi` - The unwrapped phase in radians.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let phi =  FixedI32::<U28>::from_num(-1);
+/// let wrapped_phi = wrap_phase_positive(phi);
+/// assert_eq!{ wrapped_phi.to_num::<f32>(), 5.2831855 };
+/// ```
+#[allow(clippy::needless_return)]
+pub fn wrap_phase_positive<T>(phi: T) -> T
+where
+    T: MixedNum + MixedOps + MixedPi,
+{
+    return angle::wrap_phase_positive::<angle::Radians, T>(phi);
+}
 
-    while temp_scalar < -mixed_pi {
-        temp_scalar = temp_scalar + tau;
+/// Convert the raw bits of a number with `frac_nbits` fractional bits, multiplied by 2^k, to `T`.
+///
+/// Rounded to nearest, and saturated to the range of `T` on overflow.
+/// The magnitude of `bits` must be below 2^126.
+#[allow(clippy::needless_return)]
+pub(crate) fn from_bits_scaled<T>( bits: i128, frac_nbits: i32, k: i32 ) -> T
+    where T: fixed::traits::Fixed
+{
+    let shift = k + T::FRAC_NBITS as i32 - frac_nbits;
+
+    let scaled: i128;
+    if shift < -126 {
+        scaled = 0;
+    } else if shift < 0 {
+        let half = 1i128 << (-shift - 1) as u32;
+        scaled = (bits + half) >> (-shift) as u32;
+    } else if bits == 0 {
+        scaled = 0;
+    } else if shift < 127 && (bits.unsigned_abs() >> (127 - shift) as u32) == 0 {
+        scaled = bits << shift as u32;
+    } else if bits < 0 {
+        return T::MIN;
+    } else {
+        return T::MAX;
     }
-    while mixed_pi <= temp_scalar {
-        temp_scalar = temp_scalar - tau;
+
+    match T::Bits::try_from( scaled )
+    {
+        Ok(scaled_bits) => return T::from_bits( scaled_bits ),
+        Err(_) => {
+            if scaled < 0 {
+                return T::MIN;
+            }
+            return T::MAX;
+        }
     }
-    return temp_scalar;
 }
@@ -0,0 +1,199 @@
+#![allow(clippy::needless_return, clippy::assign_op_pattern, clippy::needless_late_init)]
+
+use fixed::types::extra::{U30, U32};
+use fixed::{FixedI32, FixedI64};
+
+/// Fractional type for the polynomial evaluation.
+type Frac = FixedI32<U30>;
+
+/// Wide type for the integer part of the logarithm and the change of base.
+pub(crate) type Wide = FixedI64<U32>;
+
+/// Coefficients c1, c2, .. of the minimax polynomial for log2(1+f) in the 0=<f<1 range.
+/// Max error 4.7*10⁻⁸.
+const LOG2_COEFFICIENTS: [f64; 8] = [ 1.4426898811768403, -0.7211658059889863, 0.47868370015833894, -0.34730108925732717,
+                                      0.24186478330645908, -0.13752135452634015, 0.052059002577226385, -0.009309163755364157 ];
+
+/// Calculate log2(x) in the wide type.
+///
+/// x is normalized to x = (1+f)·2^k, where 0=<f<1, so that log2(x) = k + log2(1+f).
+/// x must be positive.
+pub(crate) fn log2_wide<T>( x: T ) -> Wide
+    where T: fixed::traits::Fixed
+{
+    let exponent = x.int_log2();
+
+    // Normalize on the raw bits, so that no precision is lost for types with few fractional bits.
+    let bits: i128 = x.to_bits().try_into().unwrap_or(i128::MAX);
+    let shift = 30 - T::FRAC_NBITS as i32 - exponent;
+
+    let mantissa_bits: i128;
+    if 0 <= shift {
+        mantissa_bits = bits << shift as u32;
+    } else {
+        mantissa_bits = bits >> (-shift) as u32;
+    }
+    let f = Frac::from_bits( (mantissa_bits - (1 << 30)) as i32 );
+
+    let mut y = Frac::from_num( LOG2_COEFFICIENTS[LOG2_COEFFICIENTS.len()-1] );
+    for c in LOG2_COEFFICIENTS.iter().rev().skip(1)
+    {
+        y = y*f + Frac::from_num( *c );
+    }
+    y = y*f;
+
+    return Wide::from_num( exponent ) + Wide::from_num( y );
+}
+
+/// Calculate the logarithm of x in the base given by the scale factor, saturating to the range of `T`.
+fn log_scaled<T>( x: T, scale: Wide ) -> T
+    where T: fixed::traits::Fixed
+{
+    if x <= T::from_num(0) {
+        return T::MIN;
+    }
+    return T::saturating_from_num( log2_wide(x)*scale );
+}
+
+/// Calculate log2(x) using normalization and a minimax polynomial.
+///
+/// x is normalized to x = (1+f)·2^k, where 0=<f<1, so that log2(x) = k + log2(1+f).
+/// log2(1+f) is approximated by an eighth order minimax polynomial, accurate to 4.7*10⁻⁸.
+/// The accuracy is further limited by the resolution of `T`.
+///
+/// Non-positive arguments return the minimum value of `T`, and results out of range of `T` saturate.
+/// Unsigned types saturate to zero for arguments below one.
+///
+/// ## Arguments
+///
+/// * `x` - The function argument.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = log::log2( FixedI32::<U16>::from_num(10) );
+/// assert_eq!{ y.to_num::<f32>(), 3.3219147 };
+///
+/// let y = log::log2( FixedI32::<U16>::from_num(0.125) );
+/// assert_eq!{ y.to_num::<f32>(), -3.0 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of the polynomial log2, compared to the `std::f64::log2` implementation.
+///
+/// The comparison is done for U28 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/log2_error.png?raw=true)
+///
+pub fn log2<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return log_scaled( x, Wide::from_num(1) );
+}
+
+/// Calculate the natural logarithm ln(x) using normalization and a minimax polynomial.
+///
+/// Calculated as ln(x) = log2(x)·ln(2), see [`log2`]. Accurate to 3.3*10⁻⁸, limited by the resolution of `T`.
+///
+/// Non-positive arguments return the minimum value of `T`, and results out of range of `T` saturate.
+///
+/// ## Arguments
+///
+/// * `x` - The function argument.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = log::ln( FixedI32::<U16>::from_num(10) );
+/// assert_eq!{ y.to_num::<f32>(), 2.3025818 };
+/// ```
+pub fn ln<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return log_scaled( x, Wide::from_num( fixed::consts::LN_2 ) );
+}
+
+/// Calculate log10(x) using normalization and a minimax polynomial.
+///
+/// Calculated as log10(x) = log2(x)·log10(2), see [`log2`]. Accurate to 1.5*10⁻⁸, limited by the resolution of `T`.
+///
+/// Non-positive arguments return the minimum value of `T`, and results out of range of `T` saturate.
+///
+/// ## Arguments
+///
+/// * `x` - The function argument.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = log::log10( FixedI32::<U16>::from_num(1000) );
+/// assert_eq!{ y.to_num::<f32>(), 3.0 };
+/// ```
+pub fn log10<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return log_scaled( x, Wide::from_num( fixed::consts::LOG10_2 ) );
+}
+
+/// Convert a power ratio to decibels, 10·log10(x).
+///
+/// Accurate to 1.5*10⁻⁷ dB, limited by the resolution of `T`.
+///
+/// A zero argument returns the minimum value of `T`, representing -∞ dB.
+/// Results out of range of `T` saturate, so `T` needs enough integer bits for the dynamic range of the measurement.
+///
+/// ## Arguments
+///
+/// * `x` - The power ratio, e.g. the power relative to full scale.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// // Power relative to full scale, in dBFS.
+/// let y = log::to_db_power( FixedI32::<U16>::from_num(1.0/1024.0) );
+/// assert_eq!{ y.to_num::<f32>(), -30.103012 };
+/// ```
+pub fn to_db_power<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return log_scaled( x, Wide::from_num( fixed::consts::LOG10_2 )*Wide::from_num(10) );
+}
+
+/// Convert an amplitude ratio to decibels, 20·log10(x).
+///
+/// Accurate to 2.9*10⁻⁷ dB, limited by the resolution of `T`.
+///
+/// A zero argument returns the minimum value of `T`, representing -∞ dB.
+/// Results out of range of `T` saturate, so `T` needs enough integer bits for the dynamic range of the measurement.
+///
+/// ## Arguments
+///
+/// * `x` - The amplitude ratio, e.g. the magnitude relative to full scale.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let y = log::to_db_amplitude( FixedI32::<U16>::from_num(0.5) );
+/// assert_eq!{ y.to_num::<f32>(), -6.0206146 };
+/// ```
+pub fn to_db_amplitude<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    return log_scaled( x, Wide::from_num( fixed::consts::LOG10_2 )*Wide::from_num(20) );
+}
@@ -0,0 +1,219 @@
+#![allow(clippy::needless_return, clippy::redundant_field_names)]
+
+use mixed_num::traits::*;
+use fixed::types::extra::U30;
+use fixed::FixedU32;
+
+/// Interpolation method used between the entries of a lookup table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Use the nearest table entry.
+    None,
+    /// Linear interpolation between the two neighbouring entries.
+    Linear,
+    /// Quadratic interpolation through three consecutive entries.
+    Quadratic,
+}
+
+/// Number of fractional bits used for the phase within a quadrant.
+const QUADRANT_BITS: u32 = 30;
+
+/// 1/2π in Q64, round(2^64/2π).
+const INV_TAU_Q64: i128 = 2935890503282001226;
+
+/// Calculate sin(x) in double precision, used to populate the tables.
+/// Taylor series, accurate to well beyond f64 precision in the 0=<x=<π/2 range.
+///
+/// ## Arguments
+///
+/// * `x` - The angle in radians.
+///
+fn sin_f64( x: f64 ) -> f64
+{
+    let x_sqr = x*x;
+    let mut term = x;
+    let mut sum  = x;
+    for n in 1..13
+    {
+        term = -term*x_sqr / ((2*n*(2*n+1)) as f64);
+        sum += term;
+    }
+    return sum;
+}
+
+/// Quarter-wave sine lookup table with `N` entries.
+///
+/// The table holds sin(θ) for `N` evenly spaced θ in the 0=<θ<π/2 range.
+/// The remaining quadrants are found through the symmetries of the sine.
+///
+/// The table is calculated once in [`SineTable::new`], and can be shared between calls.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U13, FixedI16};
+///
+/// let table = lut::SineTable::<FixedI16<U13>, 64>::new();
+///
+/// let x = FixedI16::<U13>::from_num(0.5);
+/// let y = table.sin(x, lut::Interpolation::Linear);
+/// assert_eq!{ y.to_num::<f32>(), 0.47937012 };
+///
+/// let y = table.cos(x, lut::Interpolation::Linear);
+/// assert_eq!{ y.to_num::<f32>(), 0.8774414 };
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SineTable<T, const N: usize> {
+    table: [T; N],
+}
+
+impl<T, const N: usize> SineTable<T, N>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedOps
+{
+    /// Calculate the quarter-wave table.
+    ///
+    /// `N` must be at least 2, which is checked at compile time.
+    /// The entries are saturated to the range of `T`.
+    ///
+    /// ```compile_fail
+    /// use fixed_trigonometry::*;
+    /// use fixed::{types::extra::U13, FixedI16};
+    ///
+    /// let table = lut::SineTable::<FixedI16<U13>, 1>::new();
+    /// ```
+    pub fn new() -> Self
+    {
+        const { assert!( 2 <= N, "SineTable needs at least 2 entries" ) };
+
+        let step = core::f64::consts::FRAC_PI_2 / N as f64;
+        let table = core::array::from_fn( |k| T::saturating_from_num( sin_f64( k as f64 * step ) ) );
+        return SineTable{ table: table };
+    }
+
+    /// Get table entry k, extended to the 0=<k=<2N range by symmetry around π/2.
+    fn entry( &self, k: usize ) -> T
+    {
+        if k < N {
+            return self.table[k];
+        } else if k == N {
+            return T::saturating_from_num(1);
+        } else {
+            return self.table[2*N-k];
+        }
+    }
+
+    /// Look up sin(θ) for a binary angle, where the full 32-bit range covers one turn.
+    fn lookup( &self, bam: u32, interpolation: Interpolation ) -> T
+    {
+        let quadrant = bam >> QUADRANT_BITS;
+        let mut position = bam & ((1 << QUADRANT_BITS) - 1);
+
+        // Mirror the second and fourth quadrant.
+        if quadrant & 1 == 1
+        {
+            position = (1 << QUADRANT_BITS) - position;
+        }
+
+        let scaled = position as u64 * N as u64;
+        let index  = (scaled >> QUADRANT_BITS) as usize;
+        let frac   = T::from_num( FixedU32::<U30>::from_bits( (scaled & ((1 << QUADRANT_BITS) - 1)) as u32 ) );
+
+        let y = match interpolation
+        {
+            Interpolation::None => {
+                if frac < T::from_num(0.5) {
+                    self.entry(index)
+                } else {
+                    self.entry(index+1)
+                }
+            }
+            Interpolation::Linear => {
+                let y0 = self.entry(index);
+                let y1 = self.entry(index+1);
+                y0 + (y1-y0)*frac
+            }
+            Interpolation::Quadratic => {
+                let y0 = self.entry(index);
+                let y1 = self.entry(index+1);
+                let y2 = self.entry(index+2);
+                let second_diff = (y2 - y1) - (y1 - y0);
+                y0 + (y1-y0)*frac + ((frac*(frac-T::from_num(1)))*second_diff)/T::from_num(2)
+            }
+        };
+
+        // The third and fourth quadrant are negative.
+        if 2 <= quadrant {
+            return -y;
+        }
+        return y;
+    }
+
+    /// Convert an angle in radians to a binary angle, where the full 32-bit range covers one turn.
+    ///
+    /// The raw bits are multiplied by 1/2π in Q64 in a 128-bit integer, so that the angle is not rounded to the resolution of `T`.
+    fn to_bam( x: T ) -> u32
+    {
+        let mut bits: i128 = x.to_bits().try_into().unwrap_or(0);
+        let mut frac_nbits = T::FRAC_NBITS;
+
+        // Only the 64 most significant fractional bits contribute to the 32-bit angle.
+        if 64 < frac_nbits {
+            bits >>= frac_nbits - 64;
+            frac_nbits = 64;
+        }
+
+        // The product is only needed modulo one turn, i.e. in the bits below 2^(64+frac_nbits).
+        let shift = 32 + frac_nbits;
+        let turns = bits.wrapping_mul( INV_TAU_Q64 ).wrapping_add( 1 << (shift-1) ) >> shift;
+        return turns as u32;
+    }
+
+    /// Calculate sin(x) from the table.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x`             - The angle in radians.
+    /// * `interpolation` - The interpolation method between table entries.
+    ///
+    /// `x` must be wrapped to the -π=<x<π range.
+    ///
+    /// ## Comparison and Error
+    ///
+    /// The figure below shows the error of linear interpolation in a 256 entry table, and quadratic interpolation in a 64 entry table.
+    /// The error is compared to the polynomial [`crate::sin`].
+    ///
+    /// The comparison is done for U22 signed fixed point.
+    ///
+    /// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/lut_sine_error_comparison.png?raw=true)
+    ///
+    pub fn sin( &self, x: T, interpolation: Interpolation ) -> T
+    {
+        return self.lookup( Self::to_bam(x), interpolation );
+    }
+
+    /// Calculate cos(x) from the table.
+    ///
+    /// The cosine is looked up a quarter turn ahead of the sine.
+    ///
+    /// ## Arguments
+    ///
+    /// * `x`             - The angle in radians.
+    /// * `interpolation` - The interpolation method between table entries.
+    ///
+    /// `x` must be wrapped to the -π=<x<π range.
+    ///
+    pub fn cos( &self, x: T, interpolation: Interpolation ) -> T
+    {
+        return self.lookup( Self::to_bam(x).wrapping_add(1 << QUADRANT_BITS), interpolation );
+    }
+}
+
+impl<T, const N: usize> Default for SineTable<T, N>
+    where T: fixed::traits::FixedSigned + MixedNum + MixedOps
+{
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
@@ -0,0 +1,161 @@
+//! Fast magnitude estimators of the alpha-max-plus-beta-min family \[1\].
+//!
+//! The magnitude of a complex number is estimated as `|a| ≈ α·max(|re|,|im|) + β·min(|re|,|im|)`.
+//! The error depends on the phase angle only. The stated errors are relative to the true magnitude,
+//! with the average taken uniformly over the phase angle.
+//!
+//! \[1\] R. G. Lyons, Understanding Digital Signal Processing, Third Edition, Prentice Hall, 2011, Table 13-6.
+
+#![allow(clippy::needless_return)]
+
+use mixed_num::traits::*;
+use num::complex::Complex;
+
+/// Coefficient set (α, β) of the estimator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coefficients {
+    /// α=1, β=1/2. Peak error 11.8%, average error 8.68%.
+    OneOneHalf,
+    /// α=1, β=1/4. Peak error -11.6%, average error -0.65%.
+    OneOneQuarter,
+    /// α=1, β=3/8. Peak error 6.80%, average error 4.02%.
+    OneThreeEighths,
+    /// α=7/8, β=7/16. Peak error -12.5%, average error -4.91%.
+    SevenEighthsSevenSixteenths,
+    /// α=15/16, β=15/32. Peak error -6.25%, average error 1.89%.
+    FifteenSixteenthsFifteenThirtyseconds,
+    /// α=0.96043, β=0.39782, minimizing the peak error. Peak error -3.96%, average error 1.31%.
+    ///
+    /// The shift-only estimator uses α=1-1/32-1/128 and β=1/4+1/8+1/64+1/128. Peak error 4.03%, average error 1.37%.
+    MinimumPeakError,
+}
+
+impl Coefficients {
+    /// The α and β coefficients of the set.
+    pub fn alpha_beta( &self ) -> (f64, f64)
+    {
+        match self
+        {
+            Coefficients::OneOneHalf                            => return (1.0, 0.5),
+            Coefficients::OneOneQuarter                         => return (1.0, 0.25),
+            Coefficients::OneThreeEighths                       => return (1.0, 0.375),
+            Coefficients::SevenEighthsSevenSixteenths           => return (0.875, 0.4375),
+            Coefficients::FifteenSixteenthsFifteenThirtyseconds => return (0.9375, 0.46875),
+            Coefficients::MinimumPeakError                      => return (0.96043387, 0.39782473),
+        }
+    }
+}
+
+/// The absolute value, saturated to the maximum value of `T` for the minimum value of signed types.
+fn saturating_abs<T>( x: T ) -> T
+    where T: MixedNum + MixedAbs + MixedReal
+{
+    if x.mixed_is_negative() && x == T::mixed_min_value() {
+        return T::mixed_max_value();
+    }
+    return x.mixed_abs();
+}
+
+/// Get the larger and smaller magnitude of the real and imaginary parts.
+fn max_min<T>( a: Complex<T> ) -> (T, T)
+    where T: MixedNum + MixedAbs + MixedReal
+{
+    let re = saturating_abs( a.re );
+    let im = saturating_abs( a.im );
+    if re < im {
+        return (im, re);
+    }
+    return (re, im);
+}
+
+/// Estimate the magnitude of a complex number, `α·max(|re|,|im|) + β·min(|re|,|im|)`.
+///
+/// Uses two multiplications. See [`Coefficients`] for the error of each coefficient set.
+/// The magnitude of the minimum value of signed types is saturated to the maximum value.
+///
+/// ## Arguments
+///
+/// * `a`            - The complex number.
+/// * `coefficients` - The coefficient set of the estimator.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+/// use num::complex::Complex;
+///
+/// let a = Complex::new( FixedI32::<U16>::from_num(-4), FixedI32::<U16>::from_num(3) );
+/// let y = magnitude::estimate( a, magnitude::Coefficients::MinimumPeakError );
+/// assert_eq!{ y.to_num::<f32>(), 5.0352173 };
+///
+/// let y = magnitude::estimate( Complex::new(3f32, 4f32), magnitude::Coefficients::OneOneQuarter );
+/// assert_eq!{ y, 4.75 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the relative error of the coefficient sets over the phase angle.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/magnitude_estimate_error.png?raw=true)
+///
+pub fn estimate<T>( a: Complex<T>, coefficients: Coefficients ) -> T
+    where T: MixedNum + MixedOps + MixedAbs + MixedReal
+{
+    let (max, min) = max_min( a );
+    let (alpha, beta) = coefficients.alpha_beta();
+    return T::mixed_from_num(alpha)*max + T::mixed_from_num(beta)*min;
+}
+
+/// Estimate the magnitude of a complex number using shifts and additions only.
+///
+/// Intended for cores without a hardware multiplier.
+/// The coefficients are sums of powers of two, see [`Coefficients`] for the error of each coefficient set.
+/// Results above the maximum value of `T` saturate. The magnitude of the minimum value of signed types is saturated to the maximum value.
+///
+/// ## Arguments
+///
+/// * `a`            - The complex number.
+/// * `coefficients` - The coefficient set of the estimator.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+/// use num::complex::Complex;
+///
+/// let a = Complex::new( FixedI32::<U16>::from_num(-4), FixedI32::<U16>::from_num(3) );
+/// let y = magnitude::estimate_shift( a, magnitude::Coefficients::FifteenSixteenthsFifteenThirtyseconds );
+/// assert_eq!{ y.to_num::<f32>(), 5.15625 };
+///
+/// let y = magnitude::estimate_shift( a, magnitude::Coefficients::MinimumPeakError );
+/// assert_eq!{ y.to_num::<f32>(), 5.0390625 };
+/// ```
+pub fn estimate_shift<T>( a: Complex<T>, coefficients: Coefficients ) -> T
+    where T: fixed::traits::Fixed + MixedNum + MixedAbs + MixedReal
+{
+    let (max, min) = max_min( a );
+
+    match coefficients
+    {
+        Coefficients::OneOneHalf => {
+            return max.saturating_add( min>>1 );
+        }
+        Coefficients::OneOneQuarter => {
+            return max.saturating_add( min>>2 );
+        }
+        Coefficients::OneThreeEighths => {
+            return max.saturating_add( (min>>2) + (min>>3) );
+        }
+        Coefficients::SevenEighthsSevenSixteenths => {
+            return (max - (max>>3)).saturating_add( (min>>1) - (min>>4) );
+        }
+        Coefficients::FifteenSixteenthsFifteenThirtyseconds => {
+            return (max - (max>>4)).saturating_add( (min>>1) - (min>>5) );
+        }
+        Coefficients::MinimumPeakError => {
+            return (max - (max>>5) - (max>>7)).saturating_add( (min>>2) + (min>>3) + (min>>6) + (min>>7) );
+        }
+    }
+}
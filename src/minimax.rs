@@ -0,0 +1,141 @@
+#![allow(clippy::needless_return)]
+
+use mixed_num::traits::*;
+
+/// Order of the minimax polynomial used in [`sin`] and [`cos`].
+///
+/// The coefficients are Remez-fitted to minimize the maximum absolute error on the -π/2=<x<π/2 range.
+/// The stated error bounds are for the polynomial itself, quantization of the fixed-point type comes on top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// Third order polynomial. Max error 4.5*10⁻³.
+    Third,
+    /// Fifth order polynomial. Max error 6.8*10⁻⁵.
+    Fifth,
+    /// Seventh order polynomial. Max error 5.9*10⁻⁷.
+    Seventh,
+    /// Ninth order polynomial. Max error 3.4*10⁻⁹.
+    Ninth,
+}
+
+/// Odd coefficients c1, c3, .. of the minimax polynomials in u = 2x/π, one constant for each order.
+/// Evaluating in u keeps |u|=<1, so that truncation errors are not amplified in the Horner steps.
+const SIN_COEFFICIENTS_3: [f64; 2] = [ 1.5480661860589577, -0.552557920938175 ];
+const SIN_COEFFICIENTS_5: [f64; 3] = [ 1.5703200191555204, -0.642113166986264, 0.07186085423315934 ];
+const SIN_COEFFICIENTS_7: [f64; 4] = [ 1.5707910110756178, -0.6458928495484391, 0.07943434461685944, -0.004333095292485057 ];
+const SIN_COEFFICIENTS_9: [f64; 5] = [ 1.5707962900223693, -0.6459633598658794, 0.0796884805402947, -0.004672227923193099, 0.00015082056452076037 ];
+
+/// Evaluate an odd polynomial in x using Horner's method in x².
+///
+/// ## Arguments
+///
+/// * `x`            - The polynomial argument.
+/// * `coefficients` - The odd coefficients c1, c3, .. in increasing order.
+///
+pub(crate) fn odd_polynomial<T>( x: T, coefficients: &[f64] ) -> T
+    where T: MixedNum + MixedOps
+{
+    let x_sqr = x*x;
+
+    let mut y = T::mixed_from_num( coefficients[coefficients.len()-1] );
+    for c in coefficients.iter().rev().skip(1)
+    {
+        y = y*x_sqr + T::mixed_from_num( *c );
+    }
+    return y*x;
+}
+
+/// Calculate sin(x) using a minimax polynomial of selectable order.
+///
+/// Unlike the Taylor series in [`crate::sin`], the error of the minimax polynomial is spread evenly over the range.
+/// Lower orders trade accuracy for fewer multiplications, see [`Order`] for the error bound of each order.
+///
+/// ## Arguments
+///
+/// * `x`     - The value to apply the operation to.
+/// * `order` - The order of the polynomial.
+///
+/// `x` must be wrapped to the -π=<x<π range.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let x = FixedI32::<U22>::from_num(3.1415/2.0);
+/// let y = minimax::sin(x, minimax::Order::Seventh);
+/// assert_eq!{ y.to_num::<f32>(), 0.99999905 };
+///
+/// let y = minimax::sin(x, minimax::Order::Third);
+/// assert_eq!{ y.to_num::<f32>(), 0.9955113 };
+///
+/// let y = minimax::sin(1f32, minimax::Order::Ninth);
+/// assert_eq!{ y, 0.8414709 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of the seventh and ninth order minimax polynomials, compared to the Taylor series in [`crate::sin`].
+///
+/// The comparison is done for U22 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/minimax_sine_error_comparison.png?raw=true)
+///
+pub fn sin<T>( x: T, order: Order ) -> T
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let pi_half = T::mixed_pi()/T::mixed_from_num(2);
+
+    let mut x_: T = x;
+
+    // Mirror the angle into the -π/2=<x<π/2 range of the polynomial.
+    if x_ < -pi_half {
+        x_ = -T::mixed_pi() - x;
+    } else if pi_half < x_ {
+        x_ = T::mixed_pi() - x;
+    }
+
+    let u = x_*T::mixed_from_num( core::f64::consts::FRAC_2_PI );
+
+    match order
+    {
+        Order::Third   => return odd_polynomial( u, &SIN_COEFFICIENTS_3 ),
+        Order::Fifth   => return odd_polynomial( u, &SIN_COEFFICIENTS_5 ),
+        Order::Seventh => return odd_polynomial( u, &SIN_COEFFICIENTS_7 ),
+        Order::Ninth   => return odd_polynomial( u, &SIN_COEFFICIENTS_9 ),
+    }
+}
+
+/// Calculate cos(x) using a minimax polynomial of selectable order.
+///
+/// Cos is calculated by adding a phase shift to x and running it through the minimax [`sin`].
+///
+/// ## Arguments
+///
+/// * `x`     - The value to apply the operation to.
+/// * `order` - The order of the polynomial.
+///
+/// `x` is wrapped to the -π=<x<π range in the function.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U22, FixedI32};
+///
+/// let x = FixedI32::<U22>::from_num(0);
+/// let y = minimax::cos(x, minimax::Order::Seventh);
+/// assert_eq!{ y.to_num::<f32>(), 0.9999995 };
+///
+/// let y = minimax::cos(x, minimax::Order::Ninth);
+/// assert_eq!{ y.to_num::<f32>(), 1.0 };
+/// ```
+pub fn cos<T>( x: T, order: Order ) -> T
+    where T: MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let pi_half = T::mixed_pi()/T::mixed_from_num(2);
+
+    let x_shifted = crate::wrap_phase( x + pi_half );
+    return sin( x_shifted, order );
+}
@@ -0,0 +1,161 @@
+//! Phase unwrapping.
+//!
+//! Unwrapping is the inverse of [`crate::wrap_phase`]. A sequence of wrapped phases is turned into a continuous track,
+//! by adding the wrapped difference between consecutive samples to an accumulator.
+//! The difference between consecutive samples must be less than a half turn in magnitude.
+//!
+//! The accumulator has the type `A`, which should be wider than the type of the wrapped phase `T`,
+//! so that long runs do not overflow. E.g. `FixedI64<U32>` holds about 3.4*10⁸ turns in radians.
+
+#![allow(clippy::needless_return, clippy::assign_op_pattern)]
+
+use core::marker::PhantomData;
+use mixed_num::traits::*;
+
+use crate::angle::{self, AngleUnit, Radians};
+
+/// Streaming phase unwrapper, keeping the state between samples.
+///
+/// The phase is in the unit `U`, radians by default. See [`crate::angle`] for the available units.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::{U28, U32}, FixedI32, FixedI64};
+///
+/// let mut unwrapper = phase::PhaseUnwrapper::<FixedI32<U28>, FixedI64<U32>>::new();
+///
+/// assert_eq!{ unwrapper.update( FixedI32::<U28>::from_num(3) ).to_num::<f32>(), 3.0 };
+/// assert_eq!{ unwrapper.update( FixedI32::<U28>::from_num(-3) ).to_num::<f32>(), 3.2831852 };
+/// assert_eq!{ unwrapper.update( FixedI32::<U28>::from_num(-1) ).to_num::<f32>(), 5.2831855 };
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PhaseUnwrapper<T, A, U = Radians> {
+    previous:    Option<A>,
+    accumulator: A,
+    phase:       PhantomData<T>,
+    unit:        PhantomData<U>,
+}
+
+impl<T, A, U> PhaseUnwrapper<T, A, U>
+    where T: fixed::traits::ToFixed,
+          A: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi,
+          U: AngleUnit
+{
+    /// Create an unwrapper without history.
+    pub fn new() -> Self
+    {
+        return PhaseUnwrapper{ previous: None, accumulator: A::from_num(0), phase: PhantomData, unit: PhantomData };
+    }
+
+    /// Clear the history, so that the next sample starts a new track.
+    pub fn reset( &mut self )
+    {
+        self.previous    = None;
+        self.accumulator = A::from_num(0);
+    }
+
+    /// Unwrap the next sample.
+    ///
+    /// The first sample is passed through unchanged.
+    ///
+    /// ## Arguments
+    ///
+    /// * `phase` - The wrapped phase in the unit `U`.
+    pub fn update( &mut self, phase: T ) -> A
+    {
+        let phase = A::from_num( phase );
+
+        match self.previous
+        {
+            None => {
+                self.accumulator = phase;
+            }
+            Some(previous) => {
+                self.accumulator = self.accumulator + angle::wrap_phase::<U, A>( phase - previous );
+            }
+        }
+        self.previous = Some(phase);
+        return self.accumulator;
+    }
+}
+
+impl<T, A, U> Default for PhaseUnwrapper<T, A, U>
+    where T: fixed::traits::ToFixed,
+          A: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi,
+          U: AngleUnit
+{
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+/// Unwrap a sequence of wrapped phases in radians into a continuous track.
+///
+/// See [`unwrap_phase_in`] for other angle units.
+///
+/// ## Arguments
+///
+/// * `phase`     - The wrapped phase in radians.
+/// * `unwrapped` - The unwrapped phase. The number of samples processed is the length of the shorter slice.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::{U28, U32}, FixedI32, FixedI64};
+///
+/// let phase: [FixedI32<U28>; 4] = [ 2.0, 3.0, -2.5, -1.0 ].map( FixedI32::<U28>::from_num );
+/// let mut unwrapped = [FixedI64::<U32>::from_num(0); 4];
+///
+/// phase::unwrap_phase( &phase, &mut unwrapped );
+/// assert_eq!{ unwrapped[3].to_num::<f32>(), 5.2831855 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the unwrapped phase of a chirp, compared to the true phase.
+///
+/// The comparison is done for U28 signed fixed point, with a U32 accumulator in 64 bits.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/unwrap_phase.png?raw=true)
+///
+pub fn unwrap_phase<T, A>( phase: &[T], unwrapped: &mut [A] )
+    where T: fixed::traits::ToFixed + Copy,
+          A: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    unwrap_phase_in::<Radians, T, A>( phase, unwrapped );
+}
+
+/// Unwrap a sequence of wrapped phases in the angle unit `U` into a continuous track.
+///
+/// ## Arguments
+///
+/// * `phase`     - The wrapped phase in the unit `U`.
+/// * `unwrapped` - The unwrapped phase. The number of samples processed is the length of the shorter slice.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::{U28, U32}, FixedI32, FixedI64};
+///
+/// let phase: [FixedI32<U28>; 4] = [ 0.25, 0.5, -0.25, 0.0 ].map( FixedI32::<U28>::from_num );
+/// let mut unwrapped = [FixedI64::<U32>::from_num(0); 4];
+///
+/// phase::unwrap_phase_in::<angle::Turns, _, _>( &phase, &mut unwrapped );
+/// assert_eq!{ unwrapped[3].to_num::<f32>(), 1.0 };
+/// ```
+pub fn unwrap_phase_in<U, T, A>( phase: &[T], unwrapped: &mut [A] )
+    where U: AngleUnit,
+          T: fixed::traits::ToFixed + Copy,
+          A: fixed::traits::FixedSigned + MixedNum + MixedNumSigned + MixedOps + MixedPi
+{
+    let mut unwrapper = PhaseUnwrapper::<T, A, U>::new();
+    for (x, y) in phase.iter().zip( unwrapped.iter_mut() )
+    {
+        *y = unwrapper.update( *x );
+    }
+}
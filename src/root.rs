@@ -0,0 +1,136 @@
+//! Roots of fixed-point numbers.
+//!
+//! The argument is normalized by powers of 2ⁿ to x = m·2^(n·s), where 2⁻ⁿ =< m < 1, so that x^(1/n) = 2^s·m^(1/n).
+//! The root of m is found by Newton-Raphson iterations with 60 fractional bits.
+//! m has 60-n significant bits, so the relative accuracy is about 2^(n-60)/n, limited by the resolution of `T`.
+
+#![allow(clippy::needless_return, clippy::needless_late_init)]
+
+use crate::sqrt::{Q60, denormalize};
+
+/// Largest supported root.
+pub const MAX_N: u32 = 32;
+
+/// Maximum number of Newton-Raphson iterations. The iterations stop earlier when the estimate stops decreasing.
+const MAX_ITERATIONS: usize = 200;
+
+/// Calculate the nth root of x.
+///
+/// See the [module documentation](self) for the method and accuracy.
+///
+/// Negative arguments are supported for odd n.
+/// Returns `None` for domain errors, being negative arguments for even n, n=0 and n larger than [`MAX_N`].
+///
+/// ## Arguments
+///
+/// * `x` - The argument which to calculate the root of.
+/// * `n` - The degree of the root.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let x = FixedI32::<U16>::from_num(32);
+/// assert_eq!{ root::nth_root(x, 5).unwrap().to_num::<f32>(), 2.0 };
+///
+/// let x = FixedI32::<U16>::from_num(-32);
+/// assert_eq!{ root::nth_root(x, 5).unwrap().to_num::<f32>(), -2.0 };
+/// assert_eq!{ root::nth_root(x, 4), None };
+/// ```
+pub fn nth_root<T>( x: T, n: u32 ) -> Option<T>
+    where T: fixed::traits::Fixed
+{
+    if n == 0 || MAX_N < n {
+        return None;
+    }
+
+    let zero = T::from_num(0);
+    if x == zero {
+        return Some(zero);
+    }
+
+    let negative = x < zero;
+    if negative && n & 1 == 0 {
+        return None;
+    }
+
+    // Magnitude of the raw bits, which also holds for the minimum value of signed types.
+    let bits: u128;
+    if negative {
+        let signed_bits: i128 = x.to_bits().try_into().ok()?;
+        bits = signed_bits.unsigned_abs();
+    } else {
+        bits = x.to_bits().try_into().ok()?;
+    }
+
+    // Normalize to 2⁻ⁿ =< m < 1, where the exponent e gives 2^e =< |x| < 2^(e+1).
+    let n_i32 = n as i32;
+    let exponent = 127 - bits.leading_zeros() as i32 - T::FRAC_NBITS as i32;
+    let s = (exponent + n_i32).div_euclid(n_i32);
+
+    let shift = 60 - T::FRAC_NBITS as i32 - n_i32*s;
+    let m_bits: u128;
+    if 0 <= shift {
+        m_bits = bits << shift as u32;
+    } else {
+        m_bits = bits >> (-shift) as u32;
+    }
+    let m = Q60::from_bits( m_bits as i64 );
+
+    // Starting above the root, the estimate decreases monotonically towards it.
+    // The Newton-Raphson step y = ((n-1)·y + m/y^(n-1))/n is rearranged to keep all terms below one.
+    let mut y = Q60::from_num(1);
+    for _i in 0..MAX_ITERATIONS
+    {
+        let y_next = y - ( y - m/crate::saturating_powi(y, n_i32-1) )/(n as i64);
+        if y <= y_next {
+            break;
+        }
+        y = y_next;
+    }
+
+    let root: T = denormalize( y, s );
+    if negative {
+        return Some( root.wrapping_neg() );
+    }
+    return Some(root);
+}
+
+/// Calculate the cube root of x.
+///
+/// See the [module documentation](self) for the method and accuracy.
+/// Negative arguments give negative roots.
+///
+/// ## Arguments
+///
+/// * `x` - The argument which to calculate the root of.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedI32};
+///
+/// let x = FixedI32::<U16>::from_num(-27);
+/// assert_eq!{ root::cbrt(x).to_num::<f32>(), -3.0 };
+///
+/// let x = FixedI32::<U16>::from_num(2);
+/// assert_eq!{ root::cbrt(x).to_num::<f32>(), 1.2599182 };
+/// ```
+///
+/// ## Comparison and Error
+///
+/// The figure below shows the error of the cube root, compared to the `std::f64::cbrt` implementation.
+///
+/// The comparison is done for U28 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/cbrt_error.png?raw=true)
+///
+pub fn cbrt<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    // The cube root is defined for all arguments.
+    return nth_root( x, 3 ).unwrap_or( T::from_num(0) );
+}
@@ -1,8 +1,10 @@
+#![allow(clippy::needless_return, clippy::assign_op_pattern, clippy::needless_late_init)]
+
 use mixed_num::traits::*;
 
 /// A fast implementation of the square root using the Nonlinear IIR Filter (NIIRF) method \[1\].
 /// 
-/// Only valid for positive values of x.
+/// Only valid for positive values of x. Both signed and unsigned fixed-point types are supported.
 /// Accurate to 5*10⁻⁴ with two iterations \[2\].
 /// 
 /// The structure of the estimator is illustrated below \[1\].
@@ -15,6 +17,7 @@ use mixed_num::traits::*;
 /// ```Julia
 /// β(x) = 0.763x^2-1.5688x+1.314 
 /// ```
+/// The formula, or a finer user-supplied table, can be selected in [`niirf_with_beta`].
 /// 
 /// \[1\] N.Mikami et al., A new DSP-oriented algorithm for calculation of square root using a non-linear digital filter, IEEE Trans. on Signal Processing, July 1992, pp. 1663-1669.
 /// 
@@ -44,6 +47,19 @@ use mixed_num::traits::*;
 /// assert_eq!{ y.to_num::<f32>(), 0.0f32 };
 /// ``` 
 /// 
+/// Unsigned types are supported, and have one more bit of headroom for the argument.
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U16, FixedU32};
+/// 
+/// let x = FixedU32::<U16>::from_num(40000);
+/// let y = sqrt::niirf(x, 2);
+/// assert_eq!{ y.to_num::<f32>(), 200.04688 };
+/// ``` 
+/// 
+/// Negative arguments give the root of the magnitude. The minimum value of signed types is saturated to the maximum value.
+/// 
 /// ## Accuracy and Comparison
 /// 
 /// The figure below shows error of the NIIRF implementation, compared to the `std::f32::sqrt` implementation.
@@ -56,15 +72,72 @@ use mixed_num::traits::*;
 /// 
 /// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/sqrt_error_comparison.png?raw=true)
 pub fn niirf<T>( x: T, iterations: usize ) -> T 
-    where T: MixedNum + MixedNumSigned + fixed::traits::FixedSigned + MixedNumConversion<f32> + MixedNumConversion<i32> + MixedOps
+    where T: MixedNum + fixed::traits::Fixed + MixedNumConversion<f32> + MixedNumConversion<i32> + MixedOps
+{
+    return niirf_with_beta( x, iterations, Beta::Lut );
+}
+
+/// Method for finding the acceleration factor β(x) in [`niirf_with_beta`].
+#[derive(Clone, Copy, Debug)]
+pub enum Beta<'a, T> {
+    /// The 12-entry lookup table from \[2\], used in [`niirf`].
+    Lut,
+    /// The quadratic β(x) = 0.763x²-1.5688x+1.314, at the cost of two multiplications.
+    Quadratic,
+    /// A user-supplied table, with entries for evenly spaced regions in the 0.25 =< x < 1 range.
+    ///
+    /// The optimal β for two iterations is β(x) = 1/(√x+y0), where y0 = 2x/3+0.354167 is the initial estimate.
+    /// The table must not be empty.
+    Table(&'a [T]),
+}
+
+/// The square root using the Nonlinear IIR Filter (NIIRF) method, with a selectable method for the acceleration factor β.
+/// 
+/// See [`niirf`] for a description of the method.
+/// 
+/// ## Arguments 
+///
+/// * `x`          - The argument which to calculate the root of.
+/// * `iterations` - The number of iterations to run (start with 2).
+/// * `beta`       - The method for finding the acceleration factor β.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+/// 
+/// let x = FixedI32::<U28>::from_num(0.23);
+/// let y = sqrt::niirf_with_beta(x, 2, sqrt::Beta::Quadratic);
+/// assert_eq!{ y.to_num::<f32>(), 0.4796034f32 };
+/// 
+/// let table = [ FixedI32::<U28>::from_num(0.82), FixedI32::<U28>::from_num(0.64), FixedI32::<U28>::from_num(0.53) ];
+/// let y = sqrt::niirf_with_beta(x, 2, sqrt::Beta::Table(&table));
+/// assert_eq!{ y.to_num::<f32>(), 0.47949508f32 };
+/// ``` 
+/// 
+/// ## Accuracy and Comparison
+/// 
+/// The figure below shows the error for each β method with two iterations, compared to the `std::f64::sqrt` implementation.
+/// The user-supplied table has 48 entries of the optimal β.
+/// 
+/// The comparison is done for U28 signed fixed point.
+/// 
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/niirf_beta_error_comparison.png?raw=true)
+pub fn niirf_with_beta<T>( x: T, iterations: usize, beta: Beta<T> ) -> T 
+    where T: MixedNum + fixed::traits::Fixed + MixedNumConversion<f32> + MixedNumConversion<i32> + MixedOps
 {
     if x == T::mixed_from_num(0i32)
     {
         return T::mixed_from_num(0i32);
     }
 
-    // Only works with real numbers.
-    let mut x_ = x.abs();
+    // Only works with real numbers. The minimum value of signed types saturates, as it can not be negated.
+    let mut x_ = x;
+    if x_ < T::mixed_from_num(0i32)
+    {
+        x_ = x.saturating_neg();
+    }
 
     // First we normalize x to the range 0.25 =< x < 1.
     let mut norm:i32 = 0; // Number of normalizations.
@@ -80,7 +153,7 @@ pub fn niirf<T>( x: T, iterations: usize ) -> T
     }
 
     /// LUT for getting the acceleration factor β.
-    fn beta<T>( x: T) -> T
+    fn beta_lut<T>( x: T) -> T
         where T: MixedNumConversion<f32> + MixedOps
     {   
         // There is one β value for each of the 12 regions in the range 4/16 to 16/16.
@@ -135,11 +208,36 @@ pub fn niirf<T>( x: T, iterations: usize ) -> T
         }
     }
 
+    /// Look up β in a table of evenly spaced regions in the range 4/16 to 16/16.
+    fn beta_table<T>( x: T, table: &[T] ) -> T
+        where T: fixed::traits::Fixed + MixedNumConversion<f32> + MixedOps
+    {
+        // Position in the 0.25 =< x < 1 range, scaled to 0 =< u < 1.
+        let u = (x - T::mixed_from_num(0.25))*T::mixed_from_num(4.0/3.0);
+        let u_bits: u128 = u.to_bits().try_into().unwrap_or(0);
+
+        let index = ((u_bits * table.len() as u128) >> T::FRAC_NBITS) as usize;
+        return table[index.min(table.len()-1)];
+    }
+
+    let beta_x = match beta
+    {
+        Beta::Lut          => beta_lut(x_),
+        Beta::Quadratic    => (T::mixed_from_num(0.763)*x_*x_ + T::mixed_from_num(1.314)) - T::mixed_from_num(1.5688)*x_,
+        Beta::Table(table) => beta_table(x_, table),
+    };
+
     // Estimate the square root for x, when 0.25 =< x < 1.
     let mut y = (T::mixed_from_num(2)*x_)/T::mixed_from_num(3) + T::mixed_from_num(0.354167);   // y0
     for _n in 1..iterations
     {
-        y = beta(x_)*(x_-super::powi(y,2))+y;
+        // Keep the difference positive, so that unsigned types don't overflow.
+        let y_sqr = super::powi(y,2);
+        if y_sqr <= x_ {
+            y = y + beta_x*(x_-y_sqr);
+        } else {
+            y = y - beta_x*(y_sqr-x_);
+        }
     }
 
     // Denormalize the solution.
@@ -152,4 +250,304 @@ pub fn niirf<T>( x: T, iterations: usize ) -> T
         y = y>>norm.mixed_abs() as u32;
     }
     return y;
-}
\ No newline at end of file
+}
+
+/// Calculate the square root using Newton-Raphson iterations in `T` itself.
+///
+/// Used where both floating-point and fixed-point types are accepted.
+/// x is normalized to the 0.25 =< x < 1 range by factors of four, and iterated to the precision of `T`.
+/// Only valid for positive values of x. Non-positive arguments return zero.
+pub(crate) fn mixed_newton<T>( x: T ) -> T
+    where T: MixedNum + MixedOps
+{
+    if x <= T::mixed_from_num(0i32)
+    {
+        return T::mixed_from_num(0i32);
+    }
+
+    // The constants are kept below one, as 2, 3 and 4 are not representable in types with few integer bits.
+    let half    = T::mixed_from_num(0.5);
+    let quarter = T::mixed_from_num(0.25);
+
+    // First we normalize x to the range 0.25 =< x < 1.
+    let mut x_ = x;
+    let mut norm:i32 = 0;
+    while x_< quarter
+    {
+        norm -=1;
+        x_ = (x_+x_) + (x_+x_);
+    }
+    while T::mixed_from_num(1i32) <= x_
+    {
+        norm +=1;
+        x_ = x_*quarter;
+    }
+
+    // Four iterations from the 4% seed are beyond the precision of f64.
+    let mut y = x_*T::mixed_from_num(2.0/3.0) + T::mixed_from_num(0.354167);
+    for _n in 0..4
+    {
+        y = (y + x_/y)*half;
+    }
+
+    // Denormalize the solution.
+    while 0 < norm
+    {
+        norm -=1;
+        y = y+y;
+    }
+    while norm < 0
+    {
+        norm +=1;
+        y = y*half;
+    }
+    return y;
+}
+
+/// Type for the iterations on the normalized argument.
+pub(crate) type Q60 = fixed::FixedI64<fixed::types::extra::U60>;
+
+/// Normalize a positive x to x = m·4^s, where 0.25 =< m < 1.
+///
+/// The normalization is done on the raw bits, so that no precision is lost for any type.
+/// Returns m with 60 fractional bits, and s.
+fn normalize<T>( x: T ) -> (Q60, i32)
+    where T: fixed::traits::Fixed
+{
+    let exponent = x.int_log2();
+    let s = (exponent+2).div_euclid(2);
+
+    let bits: u128 = x.to_bits().try_into().unwrap_or(0);
+    let shift = 60 - T::FRAC_NBITS as i32 - 2*s;
+
+    let m_bits: u128;
+    if 0 <= shift {
+        m_bits = bits << shift as u32;
+    } else {
+        m_bits = bits >> (-shift) as u32;
+    }
+    return ( Q60::from_bits( m_bits as i64 ), s );
+}
+
+/// Calculate y·2^k, rounded to nearest and saturated to the maximum value of `T`.
+pub(crate) fn denormalize<T>( y: Q60, k: i32 ) -> T
+    where T: fixed::traits::Fixed
+{
+    return crate::from_bits_scaled( y.to_bits() as i128, 60, k );
+}
+
+/// Seed values for 1/sqrt(x), one for each of the 12 regions in the range 4/16 to 16/16.
+/// Each value minimizes the maximum relative error in its region, which is at most 5.6%.
+const INV_SQRT_SEEDS: [f64; 12] = [ 1.8885438199983176, 1.7073741222671073, 1.5700925462513, 1.461406509452796,
+                                    1.3725830020304792, 1.2982212813470346, 1.234777041496164, 1.179814598258838,
+                                    1.1315972826098777, 1.0888488904796167, 1.050607675467804, 1.016133230340665 ];
+
+/// Calculate the inverse square root 1/sqrt(x) using Newton-Raphson iterations seeded from a lookup table.
+///
+/// x is normalized to x = m·4^s, where 0.25 =< m < 1, so that 1/sqrt(x) = 2^-s/sqrt(m).
+/// The normalization is done on the raw bits, and the iterations are calculated with 60 fractional bits, so that no precision is lost for any type.
+///
+/// Each iteration y = y·(3-m·y²)/2 roughly squares the relative error of the 5.6% seed.
+/// Two iterations are accurate to 3.3*10⁻⁵, three iterations to 1.6*10⁻⁹, relative to the result.
+/// The accuracy is further limited by the resolution of `T`.
+///
+/// Only valid for positive values of x. Non-positive arguments, and results out of range of `T`, saturate to the maximum value of `T`.
+///
+/// ## Arguments
+///
+/// * `x`          - The argument which to calculate the inverse root of.
+/// * `iterations` - The number of Newton-Raphson iterations to run (start with 3).
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let x = FixedI32::<U28>::from_num(0.25);
+/// let y = sqrt::inv_sqrt(x, 3);
+/// assert_eq!{ y.to_num::<f32>(), 2.0 };
+///
+/// let x = FixedI32::<U28>::from_num(3);
+/// let y = sqrt::inv_sqrt(x, 3);
+/// assert_eq!{ y.to_num::<f32>(), 0.57735026 };
+///
+/// let y = sqrt::inv_sqrt(x, 1);
+/// assert_eq!{ y.to_num::<f32>(), 0.57700586 };
+/// ```
+///
+/// ## Accuracy and Comparison
+///
+/// The figure below shows the relative error of the inverse square root with two and three iterations, compared to the `std::f64::sqrt` implementation.
+///
+/// The comparison is done for U28 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/inv_sqrt_error.png?raw=true)
+pub fn inv_sqrt<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::Fixed
+{
+    if x <= T::from_num(0) {
+        return T::MAX;
+    }
+
+    let (m, s) = normalize(x);
+
+    // The region is given by the four most significant fractional bits of m.
+    let region = ((m.to_bits() >> 56) - 4) as usize;
+    let mut y = Q60::from_num( INV_SQRT_SEEDS[region] );
+
+    let three = Q60::from_num(3);
+    for _n in 0..iterations
+    {
+        y = (y*(three - m*y*y))>>1;
+    }
+
+    // Denormalize the solution, 1/sqrt(x) = y·2^-s.
+    return denormalize( y, -s );
+}
+
+/// Calculate the square root using Newton-Raphson iterations.
+///
+/// x is normalized to x = m·4^s, where 0.25 =< m < 1, so that sqrt(x) = 2^s·sqrt(m).
+/// The iterations y = (y+m/y)/2 are calculated with 60 fractional bits, from the same linear seed as [`niirf`].
+///
+/// Each iteration roughly squares the relative error of the 4% seed.
+/// Two iterations are accurate to 3.2*10⁻⁷, three iterations to 5*10⁻¹⁴, relative to the result.
+/// The accuracy is further limited by the resolution of `T`.
+///
+/// Only valid for positive values of x. Negative arguments return zero.
+///
+/// ## Arguments
+///
+/// * `x`          - The argument which to calculate the root of.
+/// * `iterations` - The number of Newton-Raphson iterations to run (start with 3).
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::U28, FixedI32};
+///
+/// let x = FixedI32::<U28>::from_num(1.6);
+/// let y = sqrt::newton(x, 3);
+/// assert_eq!{ y.to_num::<f32>(), 1.264911 };
+///
+/// let y = sqrt::newton(x, 1);
+/// assert_eq!{ y.to_num::<f32>(), 1.2651286 };
+/// ```
+pub fn newton<T>( x: T, iterations: usize ) -> T
+    where T: fixed::traits::Fixed
+{
+    if x <= T::from_num(0) {
+        return T::from_num(0);
+    }
+
+    let (m, s) = normalize(x);
+
+    // Estimate the square root for m, when 0.25 =< m < 1.
+    let mut y = (Q60::from_num(2)*m)/Q60::from_num(3) + Q60::from_num(0.354167);
+    for _n in 0..iterations
+    {
+        y = (y + m/y)>>1;
+    }
+
+    // Denormalize the solution, sqrt(x) = y·2^s.
+    return denormalize( y, s );
+}
+
+/// Remainder of the digit-by-digit square root, as high and low words.
+type Remainder = (u128, u128);
+
+/// Calculate 4a+pair.
+fn shift_in( a: Remainder, pair: u128 ) -> Remainder
+{
+    return ( (a.0 << 2) | (a.1 >> 126), (a.1 << 2) | pair );
+}
+
+/// Calculate a-b, where b=<a.
+fn subtract( a: Remainder, b: Remainder ) -> Remainder
+{
+    let (low, borrow) = a.1.overflowing_sub( b.1 );
+    return ( a.0 - b.0 - borrow as u128, low );
+}
+
+/// Calculate the correctly rounded square root, using the digit-by-digit (restoring) method.
+///
+/// The root of the raw bits, scaled by 2^F for F fractional bits, is calculated one bit at a time.
+/// The remainder is kept in two words, so that all widths up to 128 bits are supported.
+/// The result is rounded to nearest, and is exact to half a unit of the last place of `T`.
+///
+/// Only valid for positive values of x. Negative arguments return zero.
+///
+/// ## Arguments
+///
+/// * `x` - The argument which to calculate the root of.
+///
+/// ## Example
+///
+/// ```
+/// use fixed_trigonometry::*;
+/// use fixed::{types::extra::{U28, U64}, FixedI32, FixedU128};
+///
+/// let x = FixedI32::<U28>::from_num(1.6);
+/// let y = sqrt::exact(x);
+/// assert_eq!{ y.to_num::<f32>(), 1.264911 };
+///
+/// let x = FixedU128::<U64>::from_num(2);
+/// let y = sqrt::exact(x);
+/// assert_eq!{ y.to_num::<f64>(), 1.4142135623730951 };
+/// ```
+///
+/// ## Accuracy and Comparison
+///
+/// The figure below shows the error of the exact and Newton square roots, compared to the `std::f64::sqrt` implementation.
+///
+/// The comparison is done for U28 signed fixed point.
+///
+/// ![Alt version](https://github.com/ErikBuer/Fixed-Trigonometry/blob/main/figures/sqrt_exact_newton_error.png?raw=true)
+pub fn exact<T>( x: T ) -> T
+    where T: fixed::traits::Fixed
+{
+    let bits: u128 = match x.to_bits().try_into()
+    {
+        Ok(bits) => bits,
+        Err(_)   => return T::from_num(0),
+    };
+    if bits == 0 {
+        return T::from_num(0);
+    }
+
+    // The root of bits·2^F, processed in pairs of bits from the most significant pair.
+    let frac_nbits = T::FRAC_NBITS;
+    let nbits = 128 - bits.leading_zeros() + frac_nbits;
+    let bit = |position: u32| -> u128 {
+        if position < frac_nbits || 128 <= position - frac_nbits {
+            return 0;
+        }
+        return (bits >> (position - frac_nbits)) & 1;
+    };
+
+    let mut root: u128 = 0;
+    let mut remainder: Remainder = (0, 0);
+    for pair in (0..nbits.div_ceil(2)).rev()
+    {
+        remainder = shift_in( remainder, (bit(2*pair+1) << 1) | bit(2*pair) );
+        let trial = shift_in( (0, root), 1 );
+        root <<= 1;
+        if trial <= remainder {
+            remainder = subtract( remainder, trial );
+            root |= 1;
+        }
+    }
+
+    // Round up when the remainder exceeds the root, as (r+0.5)² = r²+r+0.25.
+    if (0, root) < remainder {
+        root = root.saturating_add(1);
+    }
+
+    match T::Bits::try_from( root )
+    {
+        Ok(root_bits) => return T::from_bits( root_bits ),
+        Err(_) => return T::MAX,
+    }
+}
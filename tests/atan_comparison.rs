@@ -187,4 +187,201 @@ fn atan_error_comparison() -> Result<(), Box<dyn std::error::Error>>
         .draw()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+/// Error comparison of the atan2 methods
+#[test]
+fn atan2_method_error_comparison() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+    use std::f64::consts::PI as PI;
+
+    let root = BitMapBackend::new("figures/atan2_method_error_comparison.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d( -180f32..180f32, (1e-7f32..1e0f32).log_scale() )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Error [°]")
+        .x_desc("θ [°]")
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    let methods = [ (atan::Method::Octant,      "Octant",     RED),
+                    (atan::Method::Minimax,     "Minimax",    BLUE),
+                    (atan::Method::Lut,         "Lut",        GREEN),
+                    (atan::Method::Cordic(24),  "Cordic(24)", MAGENTA) ];
+
+    for (method, label, color) in methods
+    {
+        let error_series = LineSeries::new(
+            (-2000..=2000).map(|x| x as f64 *PI / 2000.0).map(|x| {
+                let y = atan::atan2_with( F::<U>::from_num(x.sin()), F::<U>::from_num(x.cos()), method );
+                // The phase wraps at ±180°.
+                let error = f64::abs( f64::atan2(x.sin(), x.cos()) - y.to_num::<f64>() ).to_degrees();
+                let error = f64::min( error, 360.0-error );
+                (x.to_degrees() as f32, f32::max( error as f32, 1e-7 ))
+            }),
+            &color);
+
+        chart
+            .draw_series( error_series )?
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Fast atan2 comparison
+#[test]
+fn atan2_fast_comparison() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+    use std::f32::consts::PI as PI;
+
+    let root = BitMapBackend::new("figures/atan2_fast_comparisons.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .right_y_label_area_size(70)
+        .build_cartesian_2d( -PI..PI, -PI..PI)?
+        .set_secondary_coord( -PI..PI, 0f32..0.1f32);
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("atan2( Im{e^(iθ)) , Re{e^(iθ))} )")
+        .x_desc("θ")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart
+        .configure_secondary_axes()
+        .y_desc("Error [°]")
+        .draw()?;
+
+    let atan2_fast_series = LineSeries::new(
+        (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, atan::atan2_fast(F::<U>::from_num(x.sin()), F::<U>::from_num(x.cos()) ).to_num::<f32>() )),
+        &RED);
+
+    // Draws a sinle line
+    chart
+        .draw_series( atan2_fast_series )?
+        .label("atan::atan2_fast")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    let std_atan_series = LineSeries::new(
+        (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::atan2(x.sin(),x.cos()) )),
+        &BLUE);
+
+    // Draws a sinle line
+    chart
+        .draw_series( std_atan_series )?
+        .label("f32::atan2")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+    let error_series = LineSeries::new(
+        (-2000..=2000).map(|x| x as f32 *PI / 2000.0).map(|x| {
+            // The phase wraps at ±180°.
+            let error = f32::abs( f32::atan2(x.sin(),x.cos()) - atan::atan2_fast(F::<U>::from_num(x.sin()), F::<U>::from_num(x.cos()) ).to_num::<f32>() ).to_degrees();
+            (x, f32::min( error, 360.0-error ))
+        }),
+        &GREEN);
+
+    // Draws a sinle line
+    chart
+        .draw_secondary_series( error_series )?
+        .label("Error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// The atan2 implementations are valid for the minimum and maximum value of the type.
+#[test]
+fn atan2_full_range()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let values = [F::<U>::MIN, F::<U>::MIN + F::<U>::DELTA, F::<U>::from_num(-1), F::<U>::from_num(0), F::<U>::from_num(1), F::<U>::MAX];
+
+    for y in values
+    {
+        for x in values
+        {
+            let expected = if y == 0 && x == 0 { 0.0 } else { f64::atan2( y.to_num::<f64>(), x.to_num::<f64>() ) };
+
+            let methods = [ ("atan2", atan::atan2( y, x ), 0.005),
+                            ("atan2_fast", atan::atan2_fast( y, x ), 0.0016),
+                            ("Minimax", atan::atan2_with( y, x, atan::Method::Minimax ), 1e-5),
+                            ("Lut", atan::atan2_with( y, x, atan::Method::Lut ), 1e-5),
+                            ("Cordic", atan::atan2_with( y, x, atan::Method::Cordic(24) ), 1e-5) ];
+
+            for (name, theta, tolerance) in methods
+            {
+                // ±π are the same angle.
+                let mut error = (theta.to_num::<f64>() - expected).abs();
+                error = error.min( (error - std::f64::consts::TAU).abs() );
+                assert!( error < tolerance, "{}({}, {}) = {}, expected {}", name, y, x, theta, expected );
+            }
+        }
+    }
+
+    for x in values
+    {
+        let error = (atan::atan( x ).to_num::<f64>() - x.to_num::<f64>().atan()).abs();
+        assert!( error < 0.005, "atan({}) = {}", x, atan::atan( x ) );
+    }
+}
+
+/// The selectable methods agree with the reference in other angle units.
+#[test]
+fn atan2_with_in_units()
+{
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U22 as U;
+
+    let methods = [ atan::Method::Octant, atan::Method::Minimax, atan::Method::Lut, atan::Method::Cordic(24) ];
+    let tolerances = [ 0.005, 1e-5, 1e-5, 1e-5 ];
+
+    for n in 0..64
+    {
+        let phi = (n as f64 - 31.5)/32.0*std::f64::consts::PI;
+        let y = F::<U>::from_num( 3.0*phi.sin() );
+        let x = F::<U>::from_num( 3.0*phi.cos() );
+        let expected = f64::atan2( y.to_num::<f64>(), x.to_num::<f64>() );
+
+        for (method, tolerance) in methods.into_iter().zip( tolerances )
+        {
+            let degrees = atan::atan2_with_in::<angle::Degrees, _>( y, x, method ).to_num::<f64>();
+            let turns   = atan::atan2_with_in::<angle::Turns, _>( y, x, method ).to_num::<f64>();
+            assert!( (degrees - expected.to_degrees()).abs() < tolerance*180.0/std::f64::consts::PI, "{:?} {} degrees", method, degrees );
+            assert!( (turns - expected/std::f64::consts::TAU).abs() < tolerance/std::f64::consts::TAU, "{:?} {} turns", method, turns );
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// The division variants handle every non-zero divisor, including those with parts at the minimum value of signed types.
+/// Where checked_div returns a result, it is compared to the division in double precision and to the plain div.
+#[test]
+fn div_full_range()
+{
+    use fixed_trigonometry::*;
+    use num::complex::Complex;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let values = [F::<U>::MIN, F::<U>::MIN + F::<U>::DELTA, -F::<U>::DELTA, F::<U>::from_num(0), F::<U>::DELTA, F::<U>::from_num(3), F::<U>::MAX];
+    let a = Complex::new( F::<U>::from_num(1), F::<U>::from_num(-3) );
+
+    for re in values
+    {
+        for im in values
+        {
+            let b = Complex::new( re, im );
+
+            let _ = complex::saturating_div( a, b );
+            let _ = complex::wrapping_div( a, b );
+            let _ = complex::saturating_recip( b );
+            let _ = complex::wrapping_recip( b );
+
+            if let Some(c) = complex::checked_div( a, b )
+            {
+                // The plain division takes the same branch, and does not overflow where checked_div does not.
+                assert_eq!{ complex::div( a, b ), c };
+
+                let b_re = re.to_num::<f64>();
+                let b_im = im.to_num::<f64>();
+                let norm = b_re*b_re + b_im*b_im;
+                let c_re = (1.0*b_re - 3.0*b_im)/norm;
+                let c_im = (-3.0*b_re - 1.0*b_im)/norm;
+
+                let tolerance = 1e-3*(1.0 + c_re.abs().max(c_im.abs()));
+                assert!{ (c.re.to_num::<f64>() - c_re).abs() < tolerance };
+                assert!{ (c.im.to_num::<f64>() - c_im).abs() < tolerance };
+            }
+        }
+    }
+
+    let zero = F::<U>::from_num(0);
+    assert_eq!{ complex::wrapping_div( a, Complex::new( F::<U>::MIN, F::<U>::MIN ) ), Complex::new( F::<U>::MAX, F::<U>::MAX ) };
+    assert_eq!{ complex::checked_div( a, Complex::new( zero, F::<U>::MIN ) ).unwrap().im.to_num::<f64>(), 1.0/32768.0 };
+    assert_eq!{ complex::div( a, Complex::new( zero, F::<U>::MIN ) ).im.to_num::<f64>(), 1.0/32768.0 };
+    assert_eq!{ complex::div( a, Complex::new( F::<U>::MIN, zero ) ).re.to_num::<f64>(), -1.0/32768.0 };
+}
+
+/// The conjugate and reciprocal of a polar complex are valid for every angle of the type, including the minimum value.
+#[test]
+fn polar_conj_full_range()
+{
+    use fixed_trigonometry::*;
+    use fixed_trigonometry::complex::Polar;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let one = F::<U>::from_num(1);
+
+    for theta in [F::<U>::MIN, F::<U>::MIN + F::<U>::DELTA, F::<U>::from_num(-2), F::<U>::from_num(0), F::<U>::from_num(3), F::<U>::MAX]
+    {
+        let x = Polar::<F<U>, angle::Radians>::new( one, theta );
+        let expected = (std::f64::consts::PI - theta.to_num::<f64>()).rem_euclid( std::f64::consts::TAU ) - std::f64::consts::PI;
+
+        let conj = x.conj();
+        assert!( (conj.theta.to_num::<f64>() - expected).abs() < 1e-6, "conj({}) = {}", theta, conj.theta );
+
+        let inv = x.inv();
+        assert!( (inv.theta.to_num::<f64>() - expected).abs() < 1e-6, "inv({}) = {}", theta, inv.theta );
+    }
+}
+
+/// The logarithm and square root are valid over the entire range of the type, including |z| above the maximum value.
+#[test]
+fn ln_sqrt_full_range()
+{
+    use fixed_trigonometry::*;
+    use num::complex::Complex;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let values = [F::<U>::MIN, F::<U>::MIN + F::<U>::DELTA, F::<U>::from_num(-3), F::<U>::from_num(0), F::<U>::from_num(0.5), F::<U>::from_num(7), F::<U>::MAX];
+
+    for re in values
+    {
+        for im in values
+        {
+            if re == 0 && im == 0 {
+                continue;
+            }
+            let (x, y) = ( re.to_num::<f64>(), im.to_num::<f64>() );
+            let norm = x.hypot( y );
+
+            let z = complex::ln( Complex::new( re, im ) );
+            assert!( (z.re.to_num::<f64>() - norm.ln()).abs() < 1e-5, "ln({}, {}) = {}", re, im, z.re );
+            assert!( (z.im.to_num::<f64>() - y.atan2( x )).abs() < 1e-5, "ln({}, {}) = {}j", re, im, z.im );
+
+            // |z| saturates above the maximum value.
+            let z = complex::sqrt( Complex::new( re, im ) );
+            if norm < F::<U>::MAX.to_num::<f64>() {
+                let t = ((norm + x.abs())/2.0).sqrt();
+                let (s_re, s_im) = if 0.0 <= x { (t, y/(2.0*t)) } else { ((y/(2.0*t)).abs(), t.copysign( y )) };
+                assert!( (z.re.to_num::<f64>() - s_re).abs() < 1e-5, "sqrt({}, {}) = {}", re, im, z.re );
+                assert!( (z.im.to_num::<f64>() - s_im).abs() < 1e-5, "sqrt({}, {}) = {}j", re, im, z.im );
+            }
+        }
+    }
+
+    let z = complex::sqrt( Complex::new( F::<U>::MIN, F::<U>::from_num(0) ) );
+    assert!( (z.im.to_num::<f64>() - 8f64.sqrt()).abs() < 1e-5 );
+}
@@ -0,0 +1,68 @@
+use plotters::prelude::*;
+
+/// Plots comparison between the CORDIC and std tanh implementations.
+#[test]
+fn compare_tanh() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let root = BitMapBackend::new("figures/tanh_comparison.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d( -4f32..4f32, -1.1f32..1.1f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("tanh(x)")
+        .x_desc("x")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.configure_mesh().draw()?;
+
+    let cordic_tanh_series = LineSeries::new(
+        (-500..=500).map(|x| x as f32 *4.0 / 500.0).map(|x| (x, hyperbolic::tanh(F::<U>::from_num(x), 24 ).to_num::<f32>() )),
+        &RED);
+
+    // Draws a sinle line
+    chart
+        .draw_series( cordic_tanh_series )?
+        .label("hyperbolic::tanh")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    let std_tanh_series = LineSeries::new(
+        (-500..=500).map(|x| x as f32 *4.0 / 500.0).map(|x| (x, x.tanh() )),
+        &BLUE);
+
+    // Draws a sinle line
+    chart
+        .draw_series( std_tanh_series )?
+        .label("f32::tanh")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+    
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *4.0 / 500.0).map(|x| (x, 10000.0*f32::abs(x.tanh()-hyperbolic::tanh( F::<U>::from_num(x), 24 ).to_num::<f32>()) )),
+            &GREEN,
+        ))?
+        .label("error*10 000")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use plotters::prelude::*;
+
+/// Plots the relative error of the alpha-max-plus-beta-min estimators over the phase angle.
+#[test]
+fn magnitude_estimate_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+    use magnitude::Coefficients;
+    use num::complex::Complex;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let root = BitMapBackend::new("figures/magnitude_estimate_error.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d( 0f32..90f32, -14f32..14f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Relative error [%]")
+        .x_desc("Phase angle [°]")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    let sets = [ (Coefficients::OneOneHalf, "α=1, β=1/2", RED),
+                 (Coefficients::OneOneQuarter, "α=1, β=1/4", BLUE),
+                 (Coefficients::OneThreeEighths, "α=1, β=3/8", GREEN),
+                 (Coefficients::SevenEighthsSevenSixteenths, "α=7/8, β=7/16", MAGENTA),
+                 (Coefficients::FifteenSixteenthsFifteenThirtyseconds, "α=15/16, β=15/32", CYAN),
+                 (Coefficients::MinimumPeakError, "α=0.96043, β=0.39782", BLACK) ];
+
+    for (coefficients, label, color) in sets
+    {
+        // Draws a sinle line
+        chart
+            .draw_series(LineSeries::new(
+                (0..=900).map(|x| x as f32 / 10.0).map(|x| {
+                    let (sin, cos) = x.to_radians().sin_cos();
+                    let a = Complex::new( F::<U>::from_num(100.0*cos), F::<U>::from_num(100.0*sin) );
+                    (x, magnitude::estimate_shift( a, coefficients ).to_num::<f32>() - 100.0)
+                }),
+                &color,
+            ))?
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// hypot and complex::abs saturate for the minimum value of signed types, which can not be negated.
+#[test]
+fn hypot_full_range()
+{
+    use fixed_trigonometry::*;
+    use num::complex::Complex;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let zero = F::<U>::from_num(0);
+    for (x, y) in [(F::<U>::MIN, zero), (zero, F::<U>::MIN), (F::<U>::MIN, F::<U>::MIN), (F::<U>::MIN, F::<U>::MAX)]
+    {
+        assert_eq!{ hypot(x, y), F::<U>::MAX };
+        assert_eq!{ complex::abs( Complex::new(x, y) ), F::<U>::MAX };
+    }
+
+    assert_eq!{ hypot(F::<U>::MAX, zero), F::<U>::MAX };
+    assert_eq!{ hypot(F::<U>::MIN + F::<U>::DELTA, zero), F::<U>::MAX };
+}
+
+/// hypot and complex::abs support types with few integer bits, where 3 and 4 are not representable.
+#[test]
+fn hypot_few_integer_bits()
+{
+    use fixed_trigonometry::*;
+    use num::complex::Complex;
+
+    use fixed::{FixedI16, FixedI32};
+    use fixed::types::extra::{U13, U29};
+
+    for (x, y) in [(0.5, 0.25), (-1.5, 2.0), (3.0, -0.125), (0.001, -0.002), (-2.5, -2.5)]
+    {
+        let expected = f64::hypot(x, y);
+
+        let r = hypot( FixedI32::<U29>::from_num(x), FixedI32::<U29>::from_num(y) ).to_num::<f64>();
+        assert!( (r - expected).abs() < 1e-6, "hypot({}, {}) = {}", x, y, r );
+
+        let r = complex::abs( Complex::new( FixedI16::<U13>::from_num(x), FixedI16::<U13>::from_num(y) ) ).to_num::<f64>();
+        assert!( (r - expected).abs() < 2e-3, "abs({}, {}) = {}", x, y, r );
+    }
+
+    assert_eq!{ hypot( FixedI32::<U29>::from_num(3.5), FixedI32::<U29>::from_num(3.5) ), FixedI32::<U29>::MAX };
+    assert_eq!{ hypot( FixedI16::<U13>::MIN, FixedI16::<U13>::from_num(0) ), FixedI16::<U13>::MAX };
+}
+
+/// The estimators saturate the magnitude of the minimum value of signed types.
+#[test]
+fn estimate_full_range()
+{
+    use fixed_trigonometry::*;
+    use magnitude::Coefficients;
+    use num::complex::Complex;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let zero = F::<U>::from_num(0);
+    let coefficients = [ Coefficients::OneOneHalf, Coefficients::OneOneQuarter, Coefficients::OneThreeEighths,
+                         Coefficients::SevenEighthsSevenSixteenths, Coefficients::FifteenSixteenthsFifteenThirtyseconds, Coefficients::MinimumPeakError ];
+
+    for c in coefficients
+    {
+        for a in [ Complex::new( F::<U>::MIN, zero ), Complex::new( zero, F::<U>::MIN ), Complex::new( F::<U>::MIN, F::<U>::MIN ) ]
+        {
+            let y = magnitude::estimate_shift( a, c );
+            assert!( F::<U>::MAX.to_num::<f64>()*0.85 < y.to_num::<f64>(), "{:?} of {:?} = {}", c, a, y );
+        }
+    }
+
+    assert_eq!{ magnitude::estimate_shift( Complex::new( F::<U>::MIN, F::<U>::MIN ), Coefficients::OneOneHalf ), F::<U>::MAX };
+
+    let y = magnitude::estimate( Complex::new( zero, F::<U>::MIN ), Coefficients::MinimumPeakError );
+    assert!( (y.to_num::<f64>() - 0.96043387*32768.0).abs() < 0.01, "estimate = {}", y );
+}
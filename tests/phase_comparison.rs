@@ -0,0 +1,168 @@
+use plotters::prelude::*;
+
+/// Plots the unwrapped phase of a chirp, compared to the true phase.
+#[test]
+fn unwrap_phase_comparison() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::{FixedI32, FixedI64};
+    use fixed::types::extra::{U28, U32};
+
+    // Linear chirp, sweeping from 0 to 0.4 turns per sample.
+    let true_phase: Vec<f64> = (0..1000).map(|n| n as f64).map(|n| core::f64::consts::TAU*0.2*n*n/1000.0 ).collect();
+    let wrapped: Vec<FixedI32<U28>> = true_phase.iter().map(|x| FixedI32::<U28>::from_num( f64::atan2(x.sin(), x.cos()) )).collect();
+
+    let mut unwrapped = vec![FixedI64::<U32>::from_num(0); wrapped.len()];
+    phase::unwrap_phase( &wrapped, &mut unwrapped );
+
+    let root = BitMapBackend::new("figures/unwrap_phase.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .right_y_label_area_size(70)
+        .build_cartesian_2d( 0f32..1000f32, -10f32..1300f32 )?
+        .set_secondary_coord( 0f32..1000f32, 0f32..1e-7f32 );
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Phase [rad]")
+        .x_desc("Sample")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart
+        .configure_secondary_axes()
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Error [rad]")
+        .draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            wrapped.iter().enumerate().map(|(n, x)| (n as f32, x.to_num::<f32>()) ),
+            &GREEN,
+        ))?
+        .label("Wrapped phase")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            unwrapped.iter().enumerate().map(|(n, x)| (n as f32, x.to_num::<f32>()) ),
+            &RED,
+        ))?
+        .label("phase::unwrap_phase")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    // Draws a sinle line
+    chart
+        .draw_secondary_series(LineSeries::new(
+            unwrapped.iter().zip( true_phase.iter() ).enumerate().map(|(n, (x, y))| (n as f32, f64::abs( x.to_num::<f64>() - y ) as f32) ),
+            &BLUE,
+        ))?
+        .label("Error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Plots the error of the constant-time phase wrapping, compared to the f64 remainder.
+#[test]
+fn wrap_phase_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U22 as U;
+    use core::f64::consts::{PI, TAU};
+
+    let root = BitMapBackend::new("figures/wrap_phase_error.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d( -500f32..500f32, 0f32..2e-5f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Error [rad]")
+        .x_desc("φ [rad]")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-5000..=5000).map(|x| x as f64 / 10.0).map(|x| {
+                let phi = F::<U>::from_num(x);
+                let reference = (phi.to_num::<f64>() + PI).rem_euclid(TAU) - PI;
+                (x as f32, f64::abs( reference - wrap_phase( phi ).to_num::<f64>() ) as f32)
+            }),
+            &RED,
+        ))?
+        .label("wrap_phase")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-5000..=5000).map(|x| x as f64 / 10.0).map(|x| {
+                let phi = F::<U>::from_num(x);
+                let reference = phi.to_num::<f64>().rem_euclid(TAU);
+                (x as f32, f64::abs( reference - wrap_phase_positive( phi ).to_num::<f64>() ) as f32)
+            }),
+            &BLUE,
+        ))?
+        .label("wrap_phase_positive")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Large floating point arguments are wrapped into the range, even though the subtracted turns are rounded.
+#[test]
+fn wrap_phase_large_float()
+{
+    use fixed_trigonometry::*;
+
+    for phi in [1e9f32, -1e9, 3.4e10, -7.7e12, f32::MAX, f32::MIN, 1e7, -123456.7]
+    {
+        let theta = angle::wrap_phase::<angle::Radians, _>( phi );
+        assert!( (-core::f32::consts::PI..core::f32::consts::PI).contains( &theta ), "wrap_phase({}) = {}", phi, theta );
+
+        let theta = angle::wrap_phase::<angle::Degrees, _>( phi );
+        assert!( (-180.0..180.0).contains( &theta ), "wrap_phase({}) = {} degrees", phi, theta );
+
+        let theta = angle::wrap_phase_positive::<angle::Radians, _>( phi );
+        assert!( (0.0..core::f32::consts::TAU).contains( &theta ), "wrap_phase_positive({}) = {}", phi, theta );
+    }
+
+    for phi in [1e18f64, -3.3e20, f64::MAX]
+    {
+        let theta = angle::wrap_phase::<angle::Radians, _>( phi );
+        assert!( (-core::f64::consts::PI..core::f64::consts::PI).contains( &theta ), "wrap_phase({}) = {}", phi, theta );
+    }
+}
@@ -0,0 +1,47 @@
+use plotters::prelude::*;
+
+/// Plots the error of the cube root, compared to the std implementation.
+#[test]
+fn cbrt_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let root = BitMapBackend::new("figures/cbrt_error.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d( -4f32..4f32, 0f32..1e-8f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Error")
+        .x_desc("x")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-1000..=1000).map(|x| x as f64 *4.0 / 1000.0).map(|x| (x as f32, f64::abs(F::<U>::from_num(x).to_num::<f64>().cbrt()-root::cbrt( F::<U>::from_num(x) ).to_num::<f64>()) as f32 )),
+            &RED,
+        ))?
+        .label("root::cbrt")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
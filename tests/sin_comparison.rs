@@ -125,4 +125,280 @@ fn poly_vs_cordic_error() -> Result<(), Box<dyn std::error::Error>>
         .draw()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Plots comparison between Errors of the minimax and Taylor polynomial sin implementations.
+#[test]
+fn minimax_vs_taylor_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U22 as U;
+    use std::f32::consts::PI as PI;
+
+    let root = BitMapBackend::new("figures/minimax_sine_error_comparison.png", (1000, 300)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d( -PI..PI, 0f32..5e-6f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Error(θ)")
+        .x_desc("θ")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.configure_mesh().draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-sin( F::<U>::from_num(x) ).to_num::<f32>()) )),
+            &BLUE,
+        ))?
+        .label("fixed_trigonometry::sin error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+    
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-minimax::sin( F::<U>::from_num(x), minimax::Order::Seventh ).to_num::<f32>()) )),
+            &RED,
+        ))?
+        .label("minimax::sin error, seventh order")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-minimax::sin( F::<U>::from_num(x), minimax::Order::Ninth ).to_num::<f32>()) )),
+            &GREEN,
+        ))?
+        .label("minimax::sin error, ninth order")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+
+/// Plots comparison between Errors of the lookup table and polynomial sin implementations.
+#[test]
+fn lut_vs_poly_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U22 as U;
+    use std::f32::consts::PI as PI;
+
+    let linear_table    = lut::SineTable::<F<U>, 256>::new();
+    let quadratic_table = lut::SineTable::<F<U>, 64>::new();
+
+    let root = BitMapBackend::new("figures/lut_sine_error_comparison.png", (1000, 300)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d( -PI..PI, 0f32..1e-5f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Error(θ)")
+        .x_desc("θ")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.configure_mesh().draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-sin( F::<U>::from_num(x) ).to_num::<f32>()) )),
+            &BLUE,
+        ))?
+        .label("fixed_trigonometry::sin error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+    
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-linear_table.sin( F::<U>::from_num(x), lut::Interpolation::Linear ).to_num::<f32>()) )),
+            &RED,
+        ))?
+        .label("lut::SineTable error, 256 entries, linear")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-quadratic_table.sin( F::<U>::from_num(x), lut::Interpolation::Quadratic ).to_num::<f32>()) )),
+            &GREEN,
+        ))?
+        .label("lut::SineTable error, 64 entries, quadratic")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+
+/// Plots comparison between Errors of the circular CORDIC, polynomial and cordic crate sin implementations.
+#[test]
+fn circular_vs_poly_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use cordic;
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U22 as U;
+    use std::f32::consts::PI as PI;
+
+    let root = BitMapBackend::new("figures/circular_sine_error_comparison.png", (1000, 300)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d( -PI..PI, 0f32..5e-6f32 )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(&WHITE.mix(0.3))
+        .y_desc("Error(θ)")
+        .x_desc("θ")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.configure_mesh().draw()?;
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-sin( F::<U>::from_num(x) ).to_num::<f32>()) )),
+            &BLUE,
+        ))?
+        .label("fixed_trigonometry::sin error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+    
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-cordic::sin( F::<U>::from_num(x) ).to_num::<f32>()) )),
+            &RED,
+        ))?
+        .label("cordic::sin error")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    // Draws a sinle line
+    chart
+        .draw_series(LineSeries::new(
+            (-500..=500).map(|x| x as f32 *PI / 500.0).map(|x| (x, f32::abs(x.sin()-circular::sin_cos( F::<U>::from_num(x), 24 ).0.to_num::<f32>()) )),
+            &GREEN,
+        ))?
+        .label("circular::sin_cos error, 24 iterations")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// The smallest lookup table interpolates through the entry at π/2, for all interpolation methods.
+#[test]
+fn lut_smallest_table()
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI16 as F;
+    use fixed::types::extra::U13 as U;
+
+    let table = lut::SineTable::<F<U>, 2>::new();
+
+    for interpolation in [lut::Interpolation::None, lut::Interpolation::Linear, lut::Interpolation::Quadratic]
+    {
+        for x in [-3.1, -1.6, -0.5, 0.0, 0.5, 1.5, 3.1]
+        {
+            let y = table.sin( F::<U>::from_num(x), interpolation ).to_num::<f32>();
+            assert!( (y - f32::sin(x)).abs() < 0.3, "sin({}) = {} with {:?}", x, y, interpolation );
+
+            let y = table.cos( F::<U>::from_num(x), interpolation ).to_num::<f32>();
+            assert!( (y - f32::cos(x)).abs() < 0.3, "cos({}) = {} with {:?}", x, y, interpolation );
+        }
+    }
+}
+
+/// The angle is converted to the table index at full precision, so 16-bit types are limited by the resolution of the output.
+#[test]
+fn lut_16_bit_accuracy()
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI16 as F;
+    use fixed::types::extra::U13 as U;
+
+    let table = lut::SineTable::<F<U>, 256>::new();
+
+    let mut max_error = 0f64;
+    for bits in (-25736..=25735).step_by(7)
+    {
+        let x = F::<U>::from_bits(bits);
+        let y = table.sin( x, lut::Interpolation::Linear ).to_num::<f64>();
+        max_error = max_error.max( (y - x.to_num::<f64>().sin()).abs() );
+    }
+    assert!( max_error < 2.0*F::<U>::DELTA.to_num::<f64>(), "max error {}", max_error );
+}
+
+/// Iteration counts at or above the number of bits of the type are clamped, and do not overflow the shifts.
+#[test]
+fn circular_iterations_above_bit_width()
+{
+    use fixed_trigonometry::*;
+
+    use fixed::{FixedI16, FixedI32};
+    use fixed::types::extra::{U13, U28};
+
+    for x in [-3.1, -1.6, -0.5, 0.0, 0.5, 1.5, 3.1]
+    {
+        let (sin, cos) = circular::sin_cos( FixedI32::<U28>::from_num(x), 40 );
+        assert!( (sin.to_num::<f64>() - f64::sin(x)).abs() < 1e-7, "sin({})", x );
+        assert!( (cos.to_num::<f64>() - f64::cos(x)).abs() < 1e-7, "cos({})", x );
+
+        let (sin, cos) = circular::sin_cos( FixedI16::<U13>::from_num(x), 24 );
+        assert!( (sin.to_num::<f64>() - f64::sin(x)).abs() < 1e-3, "sin({})", x );
+        assert!( (cos.to_num::<f64>() - f64::cos(x)).abs() < 1e-3, "cos({})", x );
+
+        let y = atan::atan2_with( FixedI32::<U28>::from_num(f64::sin(x)), FixedI32::<U28>::from_num(f64::cos(x)), atan::Method::Cordic(40) );
+        assert!( (y.to_num::<f64>() - x).abs() < 1e-7, "atan2 at {}", x );
+
+        let y = atan::atan2_with( FixedI16::<U13>::from_num(f64::sin(x)), FixedI16::<U13>::from_num(f64::cos(x)), atan::Method::Cordic(24) );
+        assert!( (y.to_num::<f64>() - x).abs() < 2e-3, "atan2 at {}", x );
+    }
+}
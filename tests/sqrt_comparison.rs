@@ -53,4 +53,185 @@ fn compare_error_sqrt() -> Result<(), Box<dyn std::error::Error>>
         .draw()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+/// Plots the relative error of the inverse square root.
+#[test]
+fn inv_sqrt_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let root = BitMapBackend::new("figures/inv_sqrt_error.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(60)
+        .right_y_label_area_size(70)
+        .build_cartesian_2d( 0.05f32..4f32, 0f32..4e-5f32)?
+        .set_secondary_coord( 0.05f32..4f32, 0f32..4e-8f32);
+
+    chart
+        .configure_mesh()
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Relative error")
+        .draw()?;
+
+    chart
+        .configure_secondary_axes()
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Relative error")
+        .draw()?;
+
+    let relative_error = |x: f64, iterations: usize| {
+        let exact = 1.0/x.sqrt();
+        (f64::abs(exact-sqrt::inv_sqrt(F::<U>::from_num(x), iterations).to_num::<f64>())/exact) as f32
+    };
+
+    chart
+        .draw_series(LineSeries::new(
+            (5..=400).map(|x| x as f64 / 100.0).map(|x| (x as f32, relative_error(x, 2) )),
+            &BLUE,
+        ))?
+        .label("sqrt::inv_sqrt, 2 iterations")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+    chart
+        .draw_secondary_series(LineSeries::new(
+            (5..=400).map(|x| x as f64 / 100.0).map(|x| (x as f32, relative_error(x, 3) )),
+            &RED,
+        ))?
+        .label("sqrt::inv_sqrt, 3 iterations (right axis)")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Plots the error of the exact and Newton square roots.
+#[test]
+fn exact_newton_sqrt_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    let root = BitMapBackend::new("figures/sqrt_exact_newton_error.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(70)
+        .build_cartesian_2d( 0.001f32..4f32, 0f32..1e-8f32)?;
+
+    chart
+        .configure_mesh()
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Error")
+        .draw()?;
+
+    chart
+        .draw_series(LineSeries::new(
+            (1..=1000).map(|x| x as f64 / 250.0).map(|x| (x as f32, f64::abs(x.sqrt()-sqrt::newton(F::<U>::from_num(x), 3).to_num::<f64>()) as f32 )),
+            &BLUE,
+        ))?
+        .label("sqrt::newton, 3 iterations")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+    chart
+        .draw_series(LineSeries::new(
+            (1..=1000).map(|x| x as f64 / 250.0).map(|x| (x as f32, f64::abs(F::<U>::from_num(x).to_num::<f64>().sqrt()-sqrt::exact(F::<U>::from_num(x)).to_num::<f64>()) as f32 )),
+            &RED,
+        ))?
+        .label("sqrt::exact")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Plots the error of the NIIRF square root for each method of finding β.
+#[test]
+fn niirf_beta_error() -> Result<(), Box<dyn std::error::Error>> 
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U28 as U;
+
+    // Optimal β for two iterations in 48 evenly spaced regions.
+    const N: usize = 48;
+    let table: Vec<F<U>> = (0..N).map(|k| 0.25 + 0.75*(k as f64 + 0.5)/N as f64)
+                                 .map(|x| F::<U>::from_num( 1.0/(x.sqrt() + 2.0*x/3.0 + 0.354167) ))
+                                 .collect();
+
+    let root = BitMapBackend::new("figures/niirf_beta_error_comparison.png", (1000, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        //.caption("title", ("sans-serif", 25).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(70)
+        .build_cartesian_2d( 0.001f32..1f32, 0f32..8e-4f32)?;
+
+    chart
+        .configure_mesh()
+        .y_label_formatter(&|y| format!("{:.1e}", y))
+        .y_desc("Error")
+        .draw()?;
+
+    let modes = [ (sqrt::Beta::Lut, "sqrt::Beta::Lut", BLUE),
+                  (sqrt::Beta::Quadratic, "sqrt::Beta::Quadratic", RED),
+                  (sqrt::Beta::Table(&table), "sqrt::Beta::Table, 48 entries", GREEN) ];
+
+    for (beta, label, color) in modes
+    {
+        chart
+            .draw_series(LineSeries::new(
+                (1..=1000).map(|x| x as f64 / 1000.0).map(|x| (x as f32, f64::abs(x.sqrt()-sqrt::niirf_with_beta(F::<U>::from_num(x), 2, beta).to_num::<f64>()) as f32 )),
+                &color,
+            ))?
+            .label(label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// niirf takes the root of the magnitude of negative arguments, and saturates the minimum value of signed types.
+#[test]
+fn niirf_negative_arguments()
+{
+    use fixed_trigonometry::*;
+
+    use fixed::FixedI32 as F;
+    use fixed::types::extra::U16 as U;
+
+    let y = sqrt::niirf( F::<U>::MIN, 2 ).to_num::<f64>();
+    let expected = F::<U>::MAX.to_num::<f64>().sqrt();
+    assert!( (y - expected).abs() < 1e-3*expected, "sqrt(MIN) = {}", y );
+
+    let y = sqrt::niirf( F::<U>::from_num(-4), 2 ).to_num::<f64>();
+    assert!( (y - 2.0).abs() < 2e-3, "sqrt(-4) = {}", y );
+}